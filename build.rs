@@ -0,0 +1,161 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+// Solutions for the default year live in `src/solutions/dayNN.rs`, earlier
+// years in `src/solutions/<year>/dayNN.rs`. Their samples are listed in
+// `samples/samples.toml` and `samples/<year>/samples.toml` respectively.
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let solutions_dir = Path::new(&manifest_dir).join("src/solutions");
+    let samples_dir = Path::new(&manifest_dir).join("samples");
+    println!("cargo:rerun-if-changed={}", solutions_dir.display());
+    println!("cargo:rerun-if-changed={}", samples_dir.display());
+
+    let mut modules = modules(&solutions_dir, "");
+    modules.push_str(&sample_tests(&samples_dir, "crate::DEFAULT_YEAR", ""));
+    let mut registered: Vec<(String, u32)> = day_numbers(&solutions_dir)
+        .into_iter()
+        .map(|day| ("crate::DEFAULT_YEAR".to_string(), day))
+        .collect();
+
+    let mut years: Vec<_> = fs::read_dir(&solutions_dir)
+        .unwrap()
+        .flat_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .filter_map(|path| {
+            let year = path.file_name()?.to_str()?.parse::<u32>().ok()?;
+            Some((year, path))
+        })
+        .collect();
+    years.sort();
+
+    for (year, dir) in years {
+        modules.push_str(&format!(
+            "pub mod y{year} {{\n{}{}}}\n",
+            self::modules(&dir, "    "),
+            sample_tests(
+                &samples_dir.join(year.to_string()),
+                &year.to_string(),
+                "    "
+            )
+        ));
+        registered.extend(
+            day_numbers(&dir)
+                .into_iter()
+                .map(|day| (year.to_string(), day)),
+        );
+    }
+
+    let pairs: Vec<String> = registered
+        .iter()
+        .map(|(year, day)| format!("({year}, {day})"))
+        .collect();
+    let modules = format!(
+        "{modules}\n#[cfg(test)]\npub const MODULE_DAYS: &[(u32, u32)] = &[{}];\n",
+        pairs.join(", ")
+    );
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out, modules).unwrap();
+}
+
+fn day_files(dir: &Path) -> Vec<PathBuf> {
+    let mut days: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .flat_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "rs")
+                && path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .is_some_and(|s| s.starts_with("day"))
+        })
+        .collect();
+    days.sort();
+    days
+}
+
+fn modules(dir: &Path, indent: &str) -> String {
+    day_files(dir)
+        .iter()
+        .map(|path| {
+            let name = path.file_stem().unwrap().to_str().unwrap();
+            format!(
+                "{indent}#[path = {:?}]\n{indent}pub mod {name};\n",
+                path.display().to_string()
+            )
+        })
+        .collect()
+}
+
+fn day_numbers(dir: &Path) -> Vec<u32> {
+    day_files(dir)
+        .iter()
+        .flat_map(|path| {
+            path.file_stem()?
+                .to_str()?
+                .trim_start_matches("day")
+                .parse::<u32>()
+                .ok()
+        })
+        .collect()
+}
+
+// One `dayNN_samples` test module per day in the manifest, with a test for
+// each part that has an expected answer.
+fn sample_tests(dir: &Path, year: &str, indent: &str) -> String {
+    let path = dir.join("samples.toml");
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return String::new();
+    };
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let manifest: toml::Table = contents
+        .parse()
+        .unwrap_or_else(|e| panic!("Invalid sample manifest {}: {e}", path.display()));
+    let samples = match manifest.get("sample") {
+        Some(toml::Value::Array(samples)) => samples.as_slice(),
+        _ => &[],
+    };
+
+    let mut days: BTreeMap<i64, Vec<String>> = BTreeMap::new();
+    for sample in samples {
+        let (Some(day), Some(file)) = (
+            sample.get("day").and_then(|d| d.as_integer()),
+            sample.get("file").and_then(|f| f.as_str()),
+        ) else {
+            panic!("Every sample in {} needs a day and file", path.display());
+        };
+
+        let stem = file
+            .trim_end_matches(".txt")
+            .replace(|c: char| !c.is_alphanumeric(), "_");
+        for part in [1, 2] {
+            if sample.get(format!("part{part}")).is_none() {
+                continue;
+            }
+
+            days.entry(day).or_default().push(format!(
+                "{indent}    #[test]\n\
+                 {indent}    fn part{part}_{stem}() {{\n\
+                 {indent}        crate::samples::assert_sample({:?}, {year}, {day}, {file:?}, {part});\n\
+                 {indent}    }}\n",
+                dir.display().to_string()
+            ));
+        }
+    }
+
+    days.into_iter()
+        .map(|(day, tests)| {
+            format!(
+                "{indent}#[cfg(test)]\n{indent}mod day{day:02}_samples {{\n{}{indent}}}\n",
+                tests.join("\n")
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,77 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ItemImpl, LitInt, parse_macro_input};
+
+/// Registers a `Solver` impl so the runner can find it by year and day. The
+/// year defaults to the crate's `DEFAULT_YEAR`.
+///
+/// ```ignore
+/// #[aoc(day = 1)]
+/// impl Solver for Day01 { ... }
+///
+/// #[aoc(year = 2024, day = 1)]
+/// impl Solver for Day01 { ... }
+/// ```
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut year: Option<LitInt> = None;
+    let mut day: Option<LitInt> = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("year") {
+            year = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("day") {
+            day = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported aoc property, expected `year` or `day`"))
+        }
+    });
+    parse_macro_input!(args with parser);
+
+    let item = parse_macro_input!(item as ItemImpl);
+
+    let Some(day) = day else {
+        return syn::Error::new_spanned(&item.self_ty, "missing `day = N` in #[aoc(...)]")
+            .to_compile_error()
+            .into();
+    };
+
+    let day = match day.base10_parse::<u32>() {
+        Ok(n) if (1..=25).contains(&n) => n,
+        _ => {
+            return syn::Error::new_spanned(day, "day must be between 1 and 25")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let year = match year {
+        None => quote! { crate::DEFAULT_YEAR },
+        Some(year) => match year.base10_parse::<u32>() {
+            Ok(n) if n >= 2015 => quote! { #n },
+            _ => {
+                return syn::Error::new_spanned(year, "year must be 2015 or later")
+                    .to_compile_error()
+                    .into();
+            }
+        },
+    };
+
+    let self_ty = &item.self_ty;
+
+    quote! {
+        #item
+
+        const _: () = {
+            #[crate::linkme::distributed_slice(crate::SOLUTIONS)]
+            #[linkme(crate = crate::linkme)]
+            static REGISTRATION: crate::Registration = crate::Registration {
+                year: #year,
+                day: #day,
+                solution: crate::parse_with::<#self_ty>,
+            };
+        };
+    }
+    .into()
+}
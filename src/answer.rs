@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+use std::{convert::Infallible, fmt, str::FromStr};
+
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Big(i128),
+    Text(String),
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved)
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match *self {
+            Answer::Signed(n) => Some(n as i128),
+            Answer::Unsigned(n) => Some(n as i128),
+            Answer::Big(n) => Some(n),
+            Answer::Text(_) | Answer::Unsolved => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_integer(), other.as_integer()) {
+            (Some(a), Some(b)) => a == b,
+            _ => match (self, other) {
+                (Answer::Text(a), Answer::Text(b)) => a == b,
+                (Answer::Unsolved, Answer::Unsolved) => true,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(n) = s.parse::<u64>() {
+            Ok(Answer::Unsigned(n))
+        } else if let Ok(n) = s.parse::<i64>() {
+            Ok(Answer::Signed(n))
+        } else if let Ok(n) = s.parse::<i128>() {
+            Ok(Answer::Big(n))
+        } else {
+            Ok(Answer::Text(s.to_string()))
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Signed: i8, i16, i32, i64);
+impl_from!(Unsigned: u8, u16, u32, u64);
+impl_from!(Big: i128);
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Answer::Signed(n as i64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Unsigned(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_numerically() {
+        assert_eq!(Answer::Signed(42), Answer::Unsigned(42));
+        assert_eq!(Answer::Big(-7), Answer::Signed(-7));
+        assert_ne!(Answer::Unsigned(42), Answer::Text("42".to_string()));
+        assert_ne!(Answer::Unsolved, Answer::Unsigned(0));
+    }
+
+    #[test]
+    fn parses_answers() {
+        assert_eq!("123".parse(), Ok(Answer::Unsigned(123)));
+        assert_eq!(" -5 ".parse(), Ok(Answer::Signed(-5)));
+        assert_eq!(
+            "170141183460469231731687303715884105727".parse(),
+            Ok(Answer::Big(i128::MAX))
+        );
+        assert_eq!("ABCD".parse(), Ok(Answer::Text("ABCD".to_string())));
+    }
+}
@@ -0,0 +1,110 @@
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Limit {
+    Runs(usize),
+    Budget(Duration),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let p95 = sorted[((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1];
+
+        Some(Stats {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            p95,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+pub fn measure<T, E>(
+    warmup: usize,
+    limit: Limit,
+    mut f: impl FnMut() -> Result<T, E>,
+) -> Result<Stats, E> {
+    for _ in 0..warmup {
+        f()?;
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+    loop {
+        let now = Instant::now();
+        f()?;
+        samples.push(now.elapsed());
+
+        let done = match limit {
+            Limit::Runs(n) => samples.len() >= n,
+            Limit::Budget(budget) => start.elapsed() >= budget,
+        };
+
+        if done {
+            break;
+        }
+    }
+
+    Ok(Stats::from_samples(&samples).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_test() {
+        let samples: Vec<_> = [5, 1, 4, 2, 3].map(Duration::from_millis).into();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert_eq!(stats.std_dev.as_micros(), 1414);
+    }
+
+    #[test]
+    fn measure_runs() {
+        let mut calls = 0;
+        let stats = measure(2, Limit::Runs(5), || {
+            calls += 1;
+            Ok::<_, ()>(())
+        })
+        .unwrap();
+
+        assert_eq!(calls, 7);
+        assert_eq!(stats.runs, 5);
+    }
+}
@@ -0,0 +1,390 @@
+use crate::{Answer, AocError, input};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use ureq::Agent;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "github.com/jwhandley/aoc-2025 v",
+    env!("CARGO_PKG_VERSION"),
+    " (ureq)"
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect { hint: Option<Hint> },
+    RateLimited { wait: Option<Duration> },
+    AlreadySolved,
+}
+
+impl Verdict {
+    pub fn classify(page: &str) -> Option<Verdict> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            let hint = if page.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(Verdict::Incorrect { hint })
+        } else if page.contains("You gave an answer too recently") {
+            // e.g. "You have 1m 5s left to wait."
+            let wait = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(wait, _)| humantime::parse_duration(wait).ok());
+            Some(Verdict::RateLimited { wait })
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    pub fn fetch_input(&self, year: u32, day: u32) -> Result<String, AocError> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call()
+            .map_err(|e| AocError::Http(format!("Request to {url} failed: {e}")))?;
+
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| AocError::Http(format!("Unable to read the response from {url}: {e}")))?;
+        match response.status().as_u16() {
+            200 => Ok(body),
+            400 | 401 => Err(AocError::Http(format!(
+                "The server rejected the session token for {url}, it may have expired"
+            ))),
+            404 => Err(AocError::Http(format!(
+                "The input for {year} day {day} is not available yet"
+            ))),
+            status => Err(AocError::Http(format!(
+                "Unexpected status {status} from {url}: {}",
+                body.trim()
+            ))),
+        }
+    }
+
+    pub fn submit(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &Answer,
+    ) -> Result<Verdict, AocError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])
+            .map_err(|e| AocError::Http(format!("Request to {url} failed: {e}")))?;
+
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| AocError::Http(format!("Unable to read the response from {url}: {e}")))?;
+        match response.status().as_u16() {
+            200 => Verdict::classify(&body).ok_or_else(|| {
+                AocError::Http(format!("Unable to understand the response from {url}"))
+            }),
+            400 | 401 => Err(AocError::Http(format!(
+                "The server rejected the session token for {url}, it may have expired"
+            ))),
+            status => Err(AocError::Http(format!(
+                "Unexpected status {status} from {url}: {}",
+                body.trim()
+            ))),
+        }
+    }
+}
+
+// Downloads the input into `path` unless it is already there. Returns whether a
+// download happened.
+pub fn fetch_cached(client: &Client, year: u32, day: u32, path: &Path) -> Result<bool, AocError> {
+    if path.exists() {
+        return Ok(false);
+    }
+
+    let input = client.fetch_input(year, day)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| AocError::io(format!("Unable to create {}", dir.display()), e))?;
+    }
+    // Written next to `path` and renamed into place, so an interrupted write
+    // never leaves a partial input that looks cached.
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, input)
+        .and_then(|_| std::fs::rename(&partial, path))
+        .map_err(|e| {
+            let _ = std::fs::remove_file(&partial);
+            AocError::io(format!("Unable to write {}", path.display()), e)
+        })?;
+
+    Ok(true)
+}
+
+// A configured `file` is the only place looked at besides `AOC_SESSION`,
+// otherwise `.aoc-session` is looked for in `root`, the config directory.
+pub fn session_token(file: Option<&Path>, root: &Path) -> Result<String, AocError> {
+    if let Ok(token) = std::env::var("AOC_SESSION")
+        && !token.trim().is_empty()
+    {
+        return Ok(token.trim().to_string());
+    }
+
+    let candidates = match file {
+        Some(file) => vec![file.to_path_buf()],
+        None => {
+            let mut candidates = vec![root.join(".aoc-session")];
+            if let Some(home) = std::env::var_os("HOME") {
+                candidates.push(PathBuf::from(home).join(".config/aoc/session"));
+            }
+            candidates
+        }
+    };
+
+    read_session_file(&candidates)
+}
+
+// Reads the first candidate that exists, skipping only those that don't.
+fn read_session_file(candidates: &[PathBuf]) -> Result<String, AocError> {
+    let token = candidates
+        .iter()
+        .map(|path| input::read_if_exists(path))
+        .find_map(Result::transpose)
+        .transpose()?;
+    if let Some(token) = token.as_deref().map(str::trim)
+        && !token.is_empty()
+    {
+        return Ok(token.to_string());
+    }
+
+    let tried: Vec<_> = candidates
+        .iter()
+        .map(|p| format!("  {}", p.display()))
+        .collect();
+    Err(AocError::Config(format!(
+        "No session token found, set AOC_SESSION or write it to one of:\n{}",
+        tried.join("\n")
+    )))
+}
+
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    // A single-threaded HTTP server that answers each request with the next
+    // canned response and hands the raw request back to the test.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap_or(0);
+                    }
+                    request.push_str(&line);
+                }
+
+                let mut body_in = vec![0; content_length];
+                let _ = reader.read_exact(&mut body_in);
+                request.push_str(&String::from_utf8_lossy(&body_in));
+                let _ = tx.send(request);
+
+                let response = format!(
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = reader.get_mut().write_all(response.as_bytes());
+            }
+        });
+
+        (url, rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fetches_and_caches_input() -> Result<(), AocError> {
+        let (url, requests) = stub::serve(vec![(200, "1,2,3\n")]);
+        let client = Client::new(&url, "abc123");
+
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("02.txt");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(fetch_cached(&client, 2025, 2, &path)?);
+        assert_eq!(std::fs::read_to_string(&path)?, "1,2,3\n");
+        assert!(!path.with_extension("partial").exists());
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/2/input HTTP/1.1"));
+        let request = request.to_ascii_lowercase();
+        assert!(request.contains("cookie: session=abc123"));
+        assert!(request.contains("user-agent: github.com/jwhandley/aoc-2025"));
+
+        // The stub only answers once, so a second download would fail.
+        assert!(!fetch_cached(&client, 2025, 2, &path)?);
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn submits_answer() -> Result<(), AocError> {
+        let (url, requests) = stub::serve(vec![(
+            200,
+            "<article><p>That's not the right answer; your answer is too low. \
+             Please wait one minute before trying again.</p></article>",
+        )]);
+        let client = Client::new(&url, "abc123");
+
+        let verdict = client.submit(2025, 7, 2, &Answer::Unsigned(40))?;
+        assert_eq!(
+            verdict,
+            Verdict::Incorrect {
+                hint: Some(Hint::TooLow)
+            }
+        );
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/7/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=40"));
+        Ok(())
+    }
+
+    #[test]
+    fn classify_test() {
+        assert_eq!(
+            Verdict::classify("<p>That's the right answer!  You are one gold star closer.</p>"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::classify("<p>That's not the right answer.  If you're stuck...</p>"),
+            Some(Verdict::Incorrect { hint: None })
+        );
+        assert_eq!(
+            Verdict::classify("<p>That's not the right answer; your answer is too high.</p>"),
+            Some(Verdict::Incorrect {
+                hint: Some(Hint::TooHigh)
+            })
+        );
+        assert_eq!(
+            Verdict::classify(
+                "<p>You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again.  You have 1m 5s left to wait.</p>"
+            ),
+            Some(Verdict::RateLimited {
+                wait: Some(Duration::from_secs(65))
+            })
+        );
+        assert_eq!(
+            Verdict::classify(
+                "<p>You don't seem to be solving the right level.  Did you already complete it?</p>"
+            ),
+            Some(Verdict::AlreadySolved)
+        );
+        assert_eq!(Verdict::classify("<p>Something else</p>"), None);
+    }
+
+    #[test]
+    fn reports_unavailable_input() {
+        let (url, _requests) = stub::serve(vec![(404, "Not found")]);
+        let client = Client::new(&url, "abc123");
+
+        let error = client.fetch_input(2025, 25).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The input for 2025 day 25 is not available yet"
+        );
+    }
+
+    #[test]
+    fn session_file_read_errors_are_reported() -> Result<(), AocError> {
+        let dir = std::env::temp_dir().join(format!("aoc-session-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("unreadable"))?;
+        std::fs::write(dir.join("session"), "abc123\n")?;
+
+        let missing = dir.join("missing");
+        assert_eq!(
+            read_session_file(&[missing.clone(), dir.join("session")])?,
+            "abc123"
+        );
+        assert!(matches!(
+            read_session_file(&[missing]),
+            Err(AocError::Config(_))
+        ));
+        // A directory exists but can't be read as a file.
+        assert!(matches!(
+            read_session_file(&[dir.join("unreadable"), dir.join("session")]),
+            Err(AocError::Io { .. })
+        ));
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
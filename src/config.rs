@@ -0,0 +1,247 @@
+use crate::{AocError, DEFAULT_YEAR, input, journal, known_answers, report::Format};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+pub const FILE_NAME: &str = ".aoc.toml";
+pub const DEFAULT_SAMPLES_DIR: &str = "./samples";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub inputs_dir: PathBuf,
+    pub samples_dir: PathBuf,
+    // Known answers for `verify` and `status`, and the journal `submit` keeps.
+    pub answers_file: PathBuf,
+    pub guesses_file: PathBuf,
+    pub year: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_file: Option<PathBuf>,
+    pub format: Format,
+    // Solver parameters keyed by day, e.g. `[params.8]`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, toml::Table>,
+    // The directory holding the config file, which relative paths in it are
+    // resolved against.
+    #[serde(skip)]
+    pub root: PathBuf,
+    #[serde(skip)]
+    pub file: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            inputs_dir: PathBuf::from(input::DEFAULT_DIR),
+            samples_dir: PathBuf::from(DEFAULT_SAMPLES_DIR),
+            answers_file: PathBuf::from(known_answers::DEFAULT_PATH),
+            guesses_file: PathBuf::from(journal::DEFAULT_PATH),
+            year: DEFAULT_YEAR,
+            session_file: None,
+            format: Format::default(),
+            params: BTreeMap::new(),
+            root: PathBuf::from("."),
+            file: None,
+        }
+    }
+}
+
+impl Config {
+    // Uses the nearest `.aoc.toml` in the current directory or its parents,
+    // then applies any `AOC_*` environment variables on top.
+    pub fn load() -> Result<Self, AocError> {
+        let cwd = std::env::current_dir()
+            .map_err(|e| AocError::io("Unable to read the current directory", e))?;
+        let mut config = match find(&cwd) {
+            Some(path) => Self::read(&path)?,
+            None => Self::default(),
+        };
+
+        config.apply_env(|name| std::env::var(name).ok())?;
+        Ok(config)
+    }
+
+    pub fn read(path: &Path) -> Result<Self, AocError> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| AocError::io(format!("Unable to read {}", path.display()), e))?;
+        let root = path.parent().unwrap_or(Path::new("."));
+
+        let mut config = Self::parse(&contents, root)
+            .map_err(|e| AocError::Config(format!("Invalid config in {}: {e}", path.display())))?;
+        config.file = Some(path.to_path_buf());
+        Ok(config)
+    }
+
+    pub fn parse(contents: &str, root: &Path) -> Result<Self, AocError> {
+        let mut config: Config = toml::from_str(contents)?;
+
+        config.inputs_dir = resolve(root, &config.inputs_dir);
+        config.samples_dir = resolve(root, &config.samples_dir);
+        config.answers_file = resolve(root, &config.answers_file);
+        config.guesses_file = resolve(root, &config.guesses_file);
+        config.session_file = config.session_file.map(|path| resolve(root, &path));
+        config.root = root.to_path_buf();
+
+        // Normalise `[params.08]` and `[params.8]` to the same key.
+        let params = std::mem::take(&mut config.params);
+        for (day, table) in params {
+            let n = day
+                .parse::<u32>()
+                .ok()
+                .filter(|n| (1..=25).contains(n))
+                .ok_or_else(|| AocError::Config(format!("Invalid day {day:?} in params")))?;
+            if config.params.insert(n.to_string(), table).is_some() {
+                return Err(AocError::Config(format!(
+                    "Params for day {n} are listed more than once"
+                )));
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<(), AocError> {
+        if let Some(dir) = var("AOC_INPUTS_DIR") {
+            self.inputs_dir = PathBuf::from(dir);
+        }
+        if let Some(dir) = var("AOC_SAMPLES_DIR") {
+            self.samples_dir = PathBuf::from(dir);
+        }
+        if let Some(year) = var("AOC_YEAR") {
+            self.year = year
+                .trim()
+                .parse()
+                .map_err(|e| AocError::Config(format!("Invalid AOC_YEAR {year:?}: {e}")))?;
+        }
+        if let Some(path) = var("AOC_SESSION_FILE") {
+            self.session_file = Some(PathBuf::from(path));
+        }
+        if let Some(format) = var("AOC_FORMAT") {
+            self.format = Format::from_str(format.trim(), true)
+                .map_err(|e| AocError::Config(format!("Invalid AOC_FORMAT {format:?}: {e}")))?;
+        }
+
+        Ok(())
+    }
+
+    // Samples for the default year sit directly in the samples directory.
+    pub fn samples_for(&self, year: u32) -> PathBuf {
+        if year == DEFAULT_YEAR {
+            self.samples_dir.clone()
+        } else {
+            self.samples_dir.join(year.to_string())
+        }
+    }
+
+    pub fn params(&self, day: u32) -> Option<&toml::Table> {
+        self.params.get(&day.to_string())
+    }
+
+    pub fn to_toml(&self) -> Result<String, AocError> {
+        toml::to_string(self).map_err(|e| AocError::Config(e.to_string()))
+    }
+}
+
+fn resolve(root: &Path, path: &Path) -> PathBuf {
+    root.join(path.strip_prefix(".").unwrap_or(path))
+}
+
+pub fn find(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+inputs_dir = "puzzles"
+guesses_file = "/var/aoc/guesses.txt"
+session_file = "/etc/aoc/session"
+format = "json"
+
+[params.08]
+connections = 1000
+"#;
+
+    #[test]
+    fn parse_test() -> Result<(), AocError> {
+        let config = Config::parse(CONFIG, Path::new("/repo"))?;
+
+        assert_eq!(config.inputs_dir, Path::new("/repo/puzzles"));
+        assert_eq!(config.samples_dir, Path::new("/repo/samples"));
+        assert_eq!(config.answers_file, Path::new("/repo/answers.txt"));
+        assert_eq!(config.guesses_file, Path::new("/var/aoc/guesses.txt"));
+        assert_eq!(
+            config.session_file.as_deref(),
+            Some(Path::new("/etc/aoc/session"))
+        );
+        assert_eq!(config.year, DEFAULT_YEAR);
+        assert_eq!(config.format, Format::Json);
+        assert_eq!(
+            config.params(8).and_then(|p| p.get("connections")),
+            Some(&toml::Value::Integer(1000))
+        );
+        assert_eq!(config.params(9), None);
+        Ok(())
+    }
+
+    #[test]
+    fn env_overrides_file() -> Result<(), AocError> {
+        let mut config = Config::parse(CONFIG, Path::new("/repo"))?;
+        config.apply_env(|name| match name {
+            "AOC_INPUTS_DIR" => Some("/tmp/inputs".to_string()),
+            "AOC_YEAR" => Some("2024".to_string()),
+            "AOC_FORMAT" => Some("CSV".to_string()),
+            _ => None,
+        })?;
+
+        assert_eq!(config.inputs_dir, Path::new("/tmp/inputs"));
+        assert_eq!(config.year, 2024);
+        assert_eq!(config.format, Format::Csv);
+
+        assert!(config.apply_env(|_| Some("x".to_string())).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        for contents in [
+            "input_dir = \"typo\"",
+            "format = \"xml\"",
+            "[params.26]\nx = 1",
+            "[params.8]\nx = 1\n[params.08]\nx = 2",
+        ] {
+            assert!(
+                Config::parse(contents, Path::new(".")).is_err(),
+                "{contents}"
+            );
+        }
+    }
+
+    #[test]
+    fn finds_config_in_parents() -> Result<(), AocError> {
+        let root = std::env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        let nested = root.join("src/solutions");
+        std::fs::create_dir_all(&nested)?;
+        std::fs::write(root.join(FILE_NAME), "year = 2024\n")?;
+
+        let path = find(&nested);
+        assert_eq!(path.as_deref(), Some(root.join(FILE_NAME).as_path()));
+        let config = Config::read(&root.join(FILE_NAME))?;
+        assert_eq!(config.year, 2024);
+        assert_eq!(
+            config.inputs_dir.display().to_string(),
+            format!("{}/inputs", root.display())
+        );
+
+        std::fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use std::{fmt, io, path::PathBuf};
+
+// Everything the library can fail with. Solvers report `Parse`,
+// `InvalidInput` and `Unsolvable`; the rest come from the tooling around them.
+#[derive(Debug)]
+pub enum AocError {
+    NoSolution {
+        year: u32,
+        day: u32,
+    },
+    MissingInput {
+        day: u32,
+        tried: Vec<PathBuf>,
+    },
+    // `snippet` is the offending line with a caret under the span, when known.
+    Parse {
+        day: u32,
+        line: usize,
+        column: usize,
+        message: String,
+        snippet: String,
+    },
+    InvalidInput(String),
+    InvalidParams(String),
+    Unsolvable(String),
+    // A malformed `.aoc.toml`, answers file, guess journal or sample manifest.
+    Config(String),
+    InvalidArgument(String),
+    Http(String),
+    Json(serde_json::Error),
+    Io {
+        context: String,
+        source: io::Error,
+    },
+}
+
+impl AocError {
+    pub fn io(context: impl Into<String>, source: io::Error) -> Self {
+        AocError::Io {
+            context: context.into(),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::NoSolution { year, day } => {
+                write!(f, "No solution found for {year} day {day}")
+            }
+            AocError::MissingInput { day, tried } => {
+                write!(f, "Unable to find input for day {day}, tried:")?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            AocError::Parse {
+                day,
+                line,
+                column,
+                message,
+                snippet,
+            } => {
+                write!(f, "Day {day}, line {line}, column {column}: {message}")?;
+                if !snippet.is_empty() {
+                    write!(f, "\n{snippet}")?;
+                }
+                Ok(())
+            }
+            AocError::InvalidInput(message)
+            | AocError::Unsolvable(message)
+            | AocError::Config(message)
+            | AocError::InvalidArgument(message)
+            | AocError::Http(message) => write!(f, "{message}"),
+            AocError::InvalidParams(message) => write!(f, "Invalid parameters: {message}"),
+            AocError::Json(e) => write!(f, "Invalid JSON: {e}"),
+            AocError::Io { context, source } => write!(f, "{context}: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            AocError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(source: io::Error) -> Self {
+        AocError::io("I/O error", source)
+    }
+}
+
+impl From<serde_json::Error> for AocError {
+    fn from(e: serde_json::Error) -> Self {
+        AocError::Json(e)
+    }
+}
+
+impl From<toml::de::Error> for AocError {
+    fn from(e: toml::de::Error) -> Self {
+        AocError::Config(e.to_string().trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn source_test() {
+        let error = AocError::io("Unable to read 03.txt", io::ErrorKind::NotFound.into());
+        let source = error.source().and_then(|e| e.downcast_ref::<io::Error>());
+        assert_eq!(source.map(io::Error::kind), Some(io::ErrorKind::NotFound));
+
+        let error: AocError = serde_json::from_str::<u32>("x").unwrap_err().into();
+        assert!(error.source().is_some());
+        assert!(AocError::Unsolvable("no path".into()).source().is_none());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{AocError, DEFAULT_YEAR};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_DIR: &str = "./inputs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Dir(PathBuf::from(DEFAULT_DIR))
+    }
+}
+
+impl InputSource {
+    pub fn is_per_day(&self) -> bool {
+        matches!(self, InputSource::Dir(_))
+    }
+
+    pub fn load(&self, year: u32, day: u32) -> Result<String, AocError> {
+        match self {
+            InputSource::Dir(dir) => {
+                let candidates = candidates(dir, year, day);
+                for path in &candidates {
+                    if let Some(input) = read_if_exists(path)? {
+                        return Ok(input);
+                    }
+                }
+                Err(AocError::MissingInput {
+                    day,
+                    tried: candidates,
+                })
+            }
+            InputSource::File(path) => read_if_exists(path)?.ok_or(AocError::MissingInput {
+                day,
+                tried: vec![path.clone()],
+            }),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input).map_err(|e| {
+                    AocError::io(format!("Unable to read input for day {day} from stdin"), e)
+                })?;
+                Ok(input)
+            }
+            InputSource::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+// Only a missing file counts as absent; anything else, such as a file that
+// can't be read or isn't UTF-8, is an error.
+pub(crate) fn read_if_exists(path: &Path) -> Result<Option<String>, AocError> {
+    match std::fs::read_to_string(path) {
+        Ok(input) => Ok(Some(input)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(AocError::io(
+            format!("Unable to read {}", path.display()),
+            e,
+        )),
+    }
+}
+
+// What to do with whitespace at the end of an input once it is normalised.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Trailing {
+    // Blank lines at the end are dropped and the last line ends in exactly
+    // one newline. Spaces at the end of the last line are kept.
+    #[default]
+    Newline,
+    // No trailing whitespace at all.
+    Trim,
+    Keep,
+}
+
+// Every input goes through this before reaching a solver, so that inputs
+// saved with a BOM, CRLF line endings or a missing or extra final newline all
+// look the same.
+pub fn normalize(input: &str, trailing: Trailing) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let input = input.replace("\r\n", "\n");
+
+    match trailing {
+        Trailing::Newline => {
+            let content = input.trim_end().len();
+            let end = input[content..]
+                .find('\n')
+                .map_or(input.len(), |i| content + i);
+
+            let mut input = input[..end].to_string();
+            if !input.is_empty() {
+                input.push('\n');
+            }
+            input
+        }
+        Trailing::Trim => input.trim_end().to_string(),
+        Trailing::Keep => input,
+    }
+}
+
+// Inputs live in `<dir>/<year>/NN.txt`, except that the default year can also
+// be kept directly in `<dir>`, which is where new ones are downloaded to.
+pub fn candidates(dir: &Path, year: u32, day: u32) -> Vec<PathBuf> {
+    let in_dir = |dir: &Path| {
+        let padded = dir.join(format!("{day:02}.txt"));
+        let plain = dir.join(format!("{day}.txt"));
+
+        if padded == plain {
+            vec![padded]
+        } else {
+            vec![padded, plain]
+        }
+    };
+
+    let mut candidates = in_dir(&dir.join(year.to_string()));
+    if year == DEFAULT_YEAR {
+        candidates.splice(0..0, in_dir(dir));
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_tried_locations() {
+        let source = InputSource::Dir(PathBuf::from("/nonexistent"));
+        let message = source.load(DEFAULT_YEAR, 3).unwrap_err().to_string();
+
+        assert_eq!(
+            message,
+            format!(
+                "Unable to find input for day 3, tried:\n  /nonexistent/03.txt\n  /nonexistent/3.txt\n  \
+                 /nonexistent/{DEFAULT_YEAR}/03.txt\n  /nonexistent/{DEFAULT_YEAR}/3.txt"
+            )
+        );
+
+        let message = source.load(2024, 12).unwrap_err().to_string();
+        assert_eq!(
+            message,
+            "Unable to find input for day 12, tried:\n  /nonexistent/2024/12.txt"
+        );
+    }
+
+    #[test]
+    fn reads_from_dir() -> Result<(), AocError> {
+        let source = InputSource::Dir(PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/samples"
+        )));
+
+        assert_eq!(
+            source.load(DEFAULT_YEAR, 3)?,
+            include_str!("../samples/03.txt")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unreadable_input_is_not_missing() -> Result<(), AocError> {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("03.txt");
+        std::fs::write(&path, [0xff, 0xfe, b'\n'])?;
+
+        for source in [InputSource::Dir(dir.clone()), InputSource::File(path)] {
+            let error = source.load(DEFAULT_YEAR, 3).unwrap_err();
+            assert!(matches!(error, AocError::Io { .. }), "{error}");
+        }
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn normalize_test() {
+        let cases = [
+            ("1\n2", "1\n2\n"),
+            ("1\n2\n", "1\n2\n"),
+            ("1\r\n2\r\n\r\n", "1\n2\n"),
+            ("\u{feff}1\n2\n \n\n", "1\n2\n"),
+            ("* +  \n", "* +  \n"),
+            ("", ""),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(normalize(input, Trailing::Newline), expected, "{input:?}");
+        }
+
+        assert_eq!(normalize("1\r\n2 \r\n", Trailing::Trim), "1\n2");
+        assert_eq!(normalize("\u{feff}1\r\n\n", Trailing::Keep), "1\n\n");
+    }
+}
@@ -0,0 +1,290 @@
+use crate::{
+    Answer, AocError,
+    client::{Hint, Verdict},
+};
+use std::{fmt, io::Write, path::Path};
+
+pub const DEFAULT_PATH: &str = "./guesses.txt";
+
+const HEADER: &str = "# Answers submitted by `aoc-2025 submit`.
+# One line per guess: <day> <part> <correct|wrong|too_high|too_low> <answer>
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Outcome {
+    // Rate limited and already solved submissions say nothing about the answer.
+    pub fn from_verdict(verdict: &Verdict) -> Option<Outcome> {
+        match verdict {
+            Verdict::Correct => Some(Outcome::Correct),
+            Verdict::Incorrect { hint: None } => Some(Outcome::Wrong),
+            Verdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => Some(Outcome::TooHigh),
+            Verdict::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => Some(Outcome::TooLow),
+            Verdict::RateLimited { .. } | Verdict::AlreadySolved => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Outcome> {
+        [
+            Outcome::Correct,
+            Outcome::Wrong,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+        ]
+        .into_iter()
+        .find(|o| o.as_str() == s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub day: u32,
+    pub part: u32,
+    pub answer: Answer,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    KnownWrong(Outcome),
+    TooHigh { bound: Answer },
+    TooLow { bound: Answer },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::KnownWrong(Outcome::TooHigh) => write!(f, "already rejected as too high"),
+            Warning::KnownWrong(Outcome::TooLow) => write!(f, "already rejected as too low"),
+            Warning::KnownWrong(_) => write!(f, "already rejected"),
+            Warning::TooHigh { bound } => write!(f, "{bound} was already too high"),
+            Warning::TooLow { bound } => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Journal {
+    guesses: Vec<Guess>,
+}
+
+impl Journal {
+    // A missing journal just means nothing has been submitted yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).map_err(|e| {
+                AocError::Config(format!(
+                    "Unable to read guesses from {}: {e}",
+                    path.display()
+                ))
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AocError::io(
+                format!("Unable to read guesses from {}", path.display()),
+                e,
+            )),
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        let mut guesses = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || AocError::Config(format!("Invalid guess on line {}: {line}", i + 1));
+            let mut fields = line.splitn(4, char::is_whitespace);
+            let day = fields
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(invalid)?;
+            let part = fields
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(invalid)?;
+            let outcome = fields.next().and_then(Outcome::parse).ok_or_else(invalid)?;
+            let answer: Answer = fields
+                .next()
+                .filter(|s| !s.trim().is_empty())
+                .ok_or_else(invalid)?
+                .parse()
+                .unwrap_or_else(|e| match e {});
+
+            guesses.push(Guess {
+                day,
+                part,
+                answer,
+                outcome,
+            });
+        }
+
+        Ok(Self { guesses })
+    }
+
+    pub fn guesses(&self, day: u32, part: u32) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |g| g.day == day && g.part == part)
+    }
+
+    // Appends the guess to the journal file as well as this copy of it.
+    pub fn record(&mut self, path: impl AsRef<Path>, guess: Guess) -> Result<(), AocError> {
+        let path = path.as_ref();
+        let exists = path.exists();
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| AocError::io(format!("Unable to open {}", path.display()), e))?;
+
+        let write_error = |e| AocError::io(format!("Unable to write {}", path.display()), e);
+        if !exists {
+            file.write_all(HEADER.as_bytes()).map_err(write_error)?;
+        }
+        writeln!(
+            file,
+            "{} {} {} {}",
+            guess.day,
+            guess.part,
+            guess.outcome.as_str(),
+            guess.answer
+        )
+        .map_err(write_error)?;
+
+        self.guesses.push(guess);
+        Ok(())
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &Answer) -> Option<Warning> {
+        if let Some(guess) = self
+            .guesses(day, part)
+            .find(|g| g.outcome != Outcome::Correct && g.answer == *answer)
+        {
+            return Some(Warning::KnownWrong(guess.outcome));
+        }
+
+        let value = answer.as_integer()?;
+        let bound = |outcome| {
+            self.guesses(day, part)
+                .filter(move |g| g.outcome == outcome)
+                .filter_map(|g| Some((g.answer.as_integer()?, &g.answer)))
+        };
+
+        if let Some((_, high)) = bound(Outcome::TooHigh)
+            .filter(|&(n, _)| value >= n)
+            .min_by_key(|&(n, _)| n)
+        {
+            return Some(Warning::TooHigh {
+                bound: high.clone(),
+            });
+        }
+
+        if let Some((_, low)) = bound(Outcome::TooLow)
+            .filter(|&(n, _)| value <= n)
+            .max_by_key(|&(n, _)| n)
+        {
+            return Some(Warning::TooLow { bound: low.clone() });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JOURNAL: &str = "# day part outcome answer
+12 1 too_high 5000
+12 1 too_low 400
+12 1 too_high 1000
+12 1 wrong 512
+12 2 wrong ABC
+";
+
+    #[test]
+    fn check_test() -> Result<(), AocError> {
+        let journal = Journal::parse(JOURNAL)?;
+        let check = |part, answer: &str| journal.check(12, part, &answer.parse().unwrap());
+
+        assert_eq!(check(1, "512"), Some(Warning::KnownWrong(Outcome::Wrong)));
+        assert_eq!(
+            check(1, "1000"),
+            Some(Warning::KnownWrong(Outcome::TooHigh))
+        );
+        assert_eq!(
+            check(1, "2000"),
+            Some(Warning::TooHigh {
+                bound: Answer::Unsigned(1000)
+            })
+        );
+        assert_eq!(
+            check(1, "-3"),
+            Some(Warning::TooLow {
+                bound: Answer::Unsigned(400)
+            })
+        );
+        assert_eq!(check(1, "600"), None);
+        assert_eq!(check(2, "ABC"), Some(Warning::KnownWrong(Outcome::Wrong)));
+        assert_eq!(check(2, "600"), None);
+        assert_eq!(journal.check(11, 1, &Answer::Unsigned(512)), None);
+        Ok(())
+    }
+
+    #[test]
+    fn records_guesses() -> Result<(), AocError> {
+        let path = std::env::temp_dir().join(format!("aoc-guesses-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut journal = Journal::load(&path)?;
+        journal.record(
+            &path,
+            Guess {
+                day: 3,
+                part: 2,
+                answer: Answer::Unsigned(42),
+                outcome: Outcome::TooLow,
+            },
+        )?;
+
+        let reloaded = Journal::load(&path)?;
+        assert_eq!(
+            reloaded.guesses(3, 2).collect::<Vec<_>>(),
+            journal.guesses(3, 2).collect::<Vec<_>>()
+        );
+        assert_eq!(reloaded.guesses(3, 2).count(), 1);
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_lines() {
+        for line in ["12 1 maybe 5", "12 1 wrong", "x 1 wrong 5"] {
+            assert!(Journal::parse(line).is_err(), "{line}");
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{Answer, AocError};
+use std::{collections::HashMap, path::Path};
+
+pub const DEFAULT_PATH: &str = "./answers.txt";
+
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    answers: HashMap<u32, [Option<Answer>; 2]>,
+}
+
+impl KnownAnswers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            AocError::io(format!("Unable to read answers from {}", path.display()), e)
+        })?;
+
+        Self::parse(&contents)
+    }
+
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        let mut answers = HashMap::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split_whitespace();
+            let day = fields
+                .next()
+                .and_then(|d| d.parse::<u32>().ok())
+                .ok_or_else(|| {
+                    AocError::Config(format!("Invalid day on line {}: {line}", i + 1))
+                })?;
+
+            let mut parts = [None, None];
+            for part in parts.iter_mut() {
+                *part = fields
+                    .next()
+                    .filter(|&s| s != "-")
+                    .and_then(|s| s.parse().ok());
+            }
+
+            if fields.next().is_some() {
+                return Err(AocError::Config(format!(
+                    "Too many answers on line {}: {line}",
+                    i + 1
+                )));
+            }
+
+            if answers.insert(day, parts).is_some() {
+                return Err(AocError::Config(format!(
+                    "Day {day} is listed more than once"
+                )));
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&Answer> {
+        let parts = self.answers.get(&day)?;
+        parts.get(part.checked_sub(1)? as usize)?.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() -> Result<(), AocError> {
+        let known = KnownAnswers::parse(
+            "# day part1 part2
+01 3 6
+12 2 -
+",
+        )?;
+
+        assert_eq!(known.get(1, 1), Some(&Answer::Unsigned(3)));
+        assert_eq!(known.get(1, 2), Some(&Answer::Unsigned(6)));
+        assert_eq!(known.get(12, 2), None);
+        assert_eq!(known.get(2, 1), None);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_duplicate_days() {
+        assert!(KnownAnswers::parse("1 3 6\n01 3 6").is_err());
+    }
+}
@@ -1,26 +1,212 @@
+mod answer;
+pub mod bench;
+pub mod client;
+pub mod config;
+mod error;
+pub mod input;
+pub mod journal;
+pub mod known_answers;
+pub mod params;
+pub mod report;
+pub mod runner;
+pub mod samples;
+pub mod scaffold;
+pub mod selection;
 mod solutions;
+pub mod status;
+pub mod supervise;
 pub mod utils;
-use crate::solutions::*;
-
-pub type Solver = (
-    fn(&str) -> Result<String, anyhow::Error>,
-    fn(&str) -> Result<String, anyhow::Error>,
-);
-
-pub fn solution_for(day: u32) -> Result<Solver, anyhow::Error> {
-    match day {
-        1 => Ok((day01::part1, day01::part2)),
-        2 => Ok((day02::part1, day02::part2)),
-        3 => Ok((day03::part1, day03::part2)),
-        4 => Ok((day04::part1, day04::part2)),
-        5 => Ok((day05::part1, day05::part2)),
-        6 => Ok((day06::part1, day06::part2)),
-        7 => Ok((day07::part1, day07::part2)),
-        8 => Ok((day08::part1, day08::part2)),
-        9 => Ok((day09::part1, day09::part2)),
-        10 => Ok((day10::part1, day10::part2)),
-        11 => Ok((day11::part1, day11::part2)),
-        12 => Ok((day12::part1, day12::part2)),
-        _ => anyhow::bail!("No solution found for day {day}"),
+pub mod watch;
+
+use serde::{Deserialize, de::DeserializeOwned};
+
+pub use answer::Answer;
+pub use error::AocError;
+
+pub const DEFAULT_YEAR: u32 = 2025;
+
+pub use aoc_macros::aoc;
+#[doc(hidden)]
+pub use linkme;
+
+pub trait Solver {
+    type Input;
+    // Named parameters, set from `.aoc.toml` or `--param name=value`. Any that
+    // are not given keep their `Default` value.
+    type Params: DeserializeOwned + Default;
+    // Applied to the input by `input::normalize` before `parse` sees it.
+    const TRAILING: input::Trailing = input::Trailing::Newline;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Answer, AocError>;
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Answer, AocError>;
+}
+
+// For days without parameters, so that passing any is an error.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+pub trait Parsed {
+    fn part1(&self) -> Result<Answer, AocError>;
+    fn part2(&self) -> Result<Answer, AocError>;
+}
+
+struct ParsedInput<S: Solver> {
+    input: S::Input,
+    params: S::Params,
+}
+
+impl<S: Solver> Parsed for ParsedInput<S> {
+    fn part1(&self) -> Result<Answer, AocError> {
+        S::part1(&self.input, &self.params)
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        S::part2(&self.input, &self.params)
+    }
+}
+
+pub type Solution = fn(&str, &toml::Table) -> Result<Box<dyn Parsed>, AocError>;
+
+#[doc(hidden)]
+pub fn parse_with<S: Solver + 'static>(
+    input: &str,
+    params: &toml::Table,
+) -> Result<Box<dyn Parsed>, AocError> {
+    let params = if params.is_empty() {
+        S::Params::default()
+    } else {
+        toml::Value::Table(params.clone())
+            .try_into()
+            .map_err(|e| AocError::InvalidParams(e.to_string().trim().to_string()))?
+    };
+
+    let input = S::parse(&input::normalize(input, S::TRAILING))?;
+    Ok(Box::new(ParsedInput::<S> { input, params }))
+}
+
+pub struct Registration {
+    pub year: u32,
+    pub day: u32,
+    pub solution: Solution,
+}
+
+#[linkme::distributed_slice]
+pub static SOLUTIONS: [Registration];
+
+pub fn solution_for(year: u32, day: u32) -> Result<Solution, AocError> {
+    SOLUTIONS
+        .iter()
+        .find(|r| r.year == year && r.day == day)
+        .map(|r| r.solution)
+        .ok_or(AocError::NoSolution { year, day })
+}
+
+pub fn days(year: u32) -> Vec<u32> {
+    let mut days: Vec<_> = SOLUTIONS
+        .iter()
+        .filter(|r| r.year == year)
+        .map(|r| r.day)
+        .collect();
+    days.sort();
+    days
+}
+
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<_> = SOLUTIONS.iter().map(|r| r.year).collect();
+    years.sort();
+    years.dedup();
+    years
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_once() {
+        for year in years() {
+            let days = days(year);
+            let mut unique = days.clone();
+            unique.dedup();
+
+            assert_eq!(days, unique);
+        }
+    }
+
+    #[test]
+    fn every_module_is_registered() {
+        let registered: Vec<_> = years()
+            .into_iter()
+            .flat_map(|year| days(year).into_iter().map(move |day| (year, day)))
+            .collect();
+        let mut modules = solutions::MODULE_DAYS.to_vec();
+        modules.sort();
+
+        assert_eq!(registered, modules);
+    }
+
+    #[test]
+    fn params_reach_the_solver() -> Result<(), AocError> {
+        let solution = solution_for(DEFAULT_YEAR, 8)?;
+        let input = include_str!("../samples/08.txt");
+
+        let parsed = solution(input, &"connections = 10".parse()?)?;
+        assert_eq!(parsed.part1()?, Answer::Unsigned(40));
+
+        for params in ["connection = 10", "connections = \"ten\""] {
+            let error = solution(input, &params.parse()?).err().unwrap();
+            assert!(matches!(error, AocError::InvalidParams(_)), "{error}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn errors_are_typed() -> Result<(), AocError> {
+        assert!(matches!(
+            solution_for(DEFAULT_YEAR, 26),
+            Err(AocError::NoSolution { day: 26, .. })
+        ));
+
+        let solution = solution_for(DEFAULT_YEAR, 1)?;
+        let error = solution("L68\nR3x\n", &toml::Table::new()).err().unwrap();
+        assert!(
+            matches!(
+                error,
+                AocError::Parse {
+                    day: 1,
+                    line: 2,
+                    column: 3,
+                    ..
+                }
+            ),
+            "{error}"
+        );
+
+        // Days that split their input by hand point at the culprit the same way.
+        for (day, input, at) in [
+            (3, "12\n3x\n", (2, 2)),
+            (4, "..\n.\n", (2, 1)),
+            (10, "[.#] (0,5) {1,2}\n", (1, 6)),
+            (11, "you: a\nout\n", (2, 1)),
+            (12, "0:\n#\n\n4x4: x\n", (4, 6)),
+        ] {
+            let error = solution_for(DEFAULT_YEAR, day)?(input, &toml::Table::new())
+                .err()
+                .unwrap();
+            let AocError::Parse {
+                line,
+                column,
+                ref snippet,
+                ..
+            } = error
+            else {
+                panic!("day {day}: {error}");
+            };
+            assert_eq!((line, column), at, "day {day}: {error}");
+            assert!(snippet.contains('^'), "day {day}: {error}");
+        }
+        Ok(())
     }
 }
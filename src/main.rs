@@ -1,64 +1,770 @@
-use anyhow::Context;
-use aoc_2025::solution_for;
+use aoc_2025::{
+    Answer, DEFAULT_YEAR,
+    bench::{self, Limit, Stats},
+    client::{self, Client, Hint, Verdict},
+    config::{self, Config},
+    days,
+    input::{self, InputSource},
+    journal::{Guess, Journal, Outcome},
+    known_answers::KnownAnswers,
+    params,
+    report::{self, Format},
+    runner::{DayRun, Record, Status, run_day},
+    samples::{self, Manifest},
+    scaffold::{self, Template},
+    selection::DaySelection,
+    solution_for, status,
+    supervise::{self, Limits, run_day_supervised},
+    watch::{self, Rebuild},
+};
 use clap::{Parser, Subcommand};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
 
 #[derive(Parser)]
 struct Args {
+    /// Puzzle year, defaults to the configured year
+    #[arg(long, global = true)]
+    year: Option<u32>,
     #[command(subcommand)]
     command: Action,
 }
 
 #[derive(Subcommand)]
 enum Action {
-    Day { day: u32 },
-    All,
+    /// Solve one or more days, e.g. `4`, `3..=7` or `1,4,9`
+    Day {
+        days: DaySelection,
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+        /// Override a solver parameter, e.g. `connections=10`
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_assignment)]
+        params: Vec<(String, toml::Value)>,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        limits: LimitArgs,
+    },
+    /// Solve every registered day
+    All {
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+        /// Directory to read `NN.txt` inputs from
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+        #[command(flatten)]
+        limits: LimitArgs,
+    },
+    /// Check every registered day against the answers in answers.txt
+    Verify {
+        /// Directory to read `NN.txt` inputs from
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+    },
+    /// Show which days are solved and verified as a calendar
+    Status {
+        /// Directory to read `NN.txt` inputs from
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+        /// Print the status as JSON instead
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check a day against the example answers listed in samples.toml
+    Sample {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+    /// Benchmark parsing and both parts over repeated runs
+    Bench {
+        /// Day to benchmark, or every registered day if omitted
+        day: Option<u32>,
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        #[arg(long, default_value_t = 10, conflicts_with = "time")]
+        runs: usize,
+        /// Time budget per measurement instead of a fixed number of runs, e.g. `2s`
+        #[arg(long, value_parser = humantime::parse_duration)]
+        time: Option<Duration>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Download puzzle inputs into the inputs directory, skipping any already there
+    Fetch {
+        /// Days to download, e.g. `4`, `1..=12` or `1,4,9`
+        days: DaySelection,
+        #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+        /// Directory to write `NN.txt` inputs to
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+    },
+    /// Create the module and sample file for a new day
+    New {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        #[arg(long, value_enum, default_value_t)]
+        template: Template,
+    },
+    /// Rebuild and re-run a day whenever its source, input or samples change
+    Watch {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        /// Build and run with optimisations
+        #[arg(long)]
+        release: bool,
+    },
+    /// Solve a part and submit the answer
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+        /// Submit even if the guess journal says the answer is wrong
+        #[arg(long)]
+        force: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Print the settings resolved from .aoc.toml and the environment
+    Config,
+    /// Solve a single part with the input read from stdin, reporting the result as JSON
+    #[command(hide = true)]
+    Worker {
+        year: u32,
+        day: u32,
+        part: u32,
+        /// Solver parameters as a TOML table
+        #[arg(long, default_value = "")]
+        params: String,
+    },
+}
+
+#[derive(clap::Args)]
+struct InputArgs {
+    /// Read the input from this file, or from stdin when `-`
+    #[arg(long, conflicts_with_all = ["input_dir", "input_text"])]
+    input: Option<PathBuf>,
+    /// Directory to read `NN.txt` inputs from
+    #[arg(long, conflicts_with = "input_text")]
+    input_dir: Option<PathBuf>,
+    /// Use this string as the input
+    #[arg(long)]
+    input_text: Option<String>,
+}
+
+#[derive(clap::Args)]
+struct LimitArgs {
+    /// Kill a part that runs for longer than this, e.g. `30s`
+    #[arg(long, value_parser = humantime::parse_duration)]
+    timeout: Option<Duration>,
+    /// Cap the address space of each part, e.g. `512M`
+    #[arg(long, value_parser = supervise::parse_bytes)]
+    max_memory: Option<u64>,
+}
+
+impl LimitArgs {
+    fn limits(&self) -> Option<Limits> {
+        if self.timeout.is_none() && self.max_memory.is_none() {
+            return None;
+        }
+
+        Some(Limits {
+            timeout: self.timeout,
+            max_memory: self.max_memory,
+        })
+    }
+}
+
+impl InputArgs {
+    fn source(self, config: &Config) -> InputSource {
+        match (self.input, self.input_dir, self.input_text) {
+            (Some(path), ..) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), ..) => InputSource::File(path),
+            (_, Some(dir), _) => InputSource::Dir(dir),
+            (.., Some(text)) => InputSource::Inline(text),
+            _ => InputSource::Dir(config.inputs_dir.clone()),
+        }
+    }
 }
 
 fn main() -> Result<(), anyhow::Error> {
     let args = Args::parse();
+    let config = Config::load()?;
+    let year = args.year.unwrap_or(config.year);
     match args.command {
-        Action::Day { day } => solve_day(day),
-        Action::All => {
-            println!("Solving all days");
-            let now = std::time::Instant::now();
-            for day in 1..=25 {
-                if let Ok((part1, part2)) = solution_for(day) {
-                    let input = std::fs::read_to_string(format!("./inputs/{day:02}.txt"))
-                        .with_context(|| format!("Unable to find input for day {day}"))?;
-
-                    println!("Solving day {day}");
-                    let now = std::time::Instant::now();
-                    let r1 = part1(&input)?;
-                    println!("Part 1: {r1}");
-
-                    let r2 = part2(&input)?;
-                    println!("Part 2: {r2}");
-
-                    println!("Solved in {:>10}", format!("{:.02?}", now.elapsed()));
+        Action::Day {
+            days,
+            part,
+            format,
+            params,
+            input,
+            limits,
+        } => {
+            if !params.is_empty() && days.resolve(&self::days(year)).len() > 1 {
+                anyhow::bail!("--param can only be used with a single day");
+            }
+
+            run(
+                year,
+                &config.guesses_file,
+                &days,
+                part,
+                format.unwrap_or(config.format),
+                &input.source(&config),
+                limits.limits(),
+                |day| params::merge(config.params(day), &params),
+            )
+        }
+        Action::All {
+            part,
+            format,
+            input_dir,
+            limits,
+        } => run(
+            year,
+            &config.guesses_file,
+            &DaySelection::all(),
+            part,
+            format.unwrap_or(config.format),
+            &dir_source(input_dir, &config),
+            limits.limits(),
+            |day| config_params(&config, day),
+        ),
+        Action::Fetch {
+            days,
+            base_url,
+            input_dir,
+        } => {
+            let dir = input_dir.unwrap_or_else(|| config.inputs_dir.clone());
+            let client = Client::new(
+                &base_url,
+                &client::session_token(config.session_file.as_deref(), &config.root)?,
+            );
+
+            for day in days.resolve(&(1..=25).collect::<Vec<_>>()) {
+                let candidates = input::candidates(&dir, year, day);
+                if let Some(path) = candidates.iter().find(|p| p.exists()) {
+                    println!("Day {day}: already cached at {}", path.display());
+                    continue;
+                }
+
+                client::fetch_cached(&client, year, day, &candidates[0])?;
+                println!("Day {day}: saved to {}", candidates[0].display());
+            }
+
+            Ok(())
+        }
+        Action::New { day, template } => {
+            if solution_for(year, day).is_ok() {
+                anyhow::bail!("Day {day} of {year} already has a solution");
+            }
+
+            for path in scaffold::create(&config, year, day, template)? {
+                println!("Created {}", path.display());
+            }
+            println!(
+                "Paste the example into the sample file and fill in its answers in {}",
+                samples::MANIFEST
+            );
+            Ok(())
+        }
+        Action::Watch { day, release } => {
+            let mut seen = None;
+            let mut previous = BTreeMap::new();
+
+            loop {
+                let snapshot = watch::snapshot(&config, year, day);
+                if seen.as_ref() != Some(&snapshot) {
+                    seen = Some(snapshot);
+                    println!("Change detected, re-running day {day}");
+
+                    match watch::rebuild(&config, year, day, release) {
+                        Ok(Rebuild::Solved(current)) => {
+                            for line in watch::diff(&previous, &current) {
+                                println!("{line}");
+                            }
+                            previous = current;
+                        }
+                        Ok(Rebuild::BuildFailed) => println!("Build failed"),
+                        Ok(Rebuild::SamplesFailed) => println!("Sample tests failed"),
+                        Err(e) => println!("Unable to run day {day}: {e:#}"),
+                    }
                     println!("--------------------");
                 }
+
+                std::thread::sleep(Duration::from_millis(500));
+            }
+        }
+        Action::Submit {
+            day,
+            part,
+            base_url,
+            force,
+            input,
+        } => submit(
+            year,
+            day,
+            part,
+            &base_url,
+            force,
+            &input.source(&config),
+            &config,
+        ),
+        Action::Config => {
+            match &config.file {
+                Some(path) => println!("# Loaded from {}", path.display()),
+                None => println!("# No {} found, using defaults", config::FILE_NAME),
+            }
+            print!("{}", config.to_toml()?);
+            Ok(())
+        }
+        Action::Worker {
+            year,
+            day,
+            part,
+            params,
+        } => {
+            let params: toml::Table = params.parse()?;
+            let input = InputSource::Stdin.load(year, day)?;
+            let report = supervise::work(year, day, part, &input, &params);
+            println!("{}", serde_json::to_string(&report)?);
+            Ok(())
+        }
+        Action::Sample { day } => check_samples(year, day, &config),
+        Action::Verify { input_dir } => verify(year, &dir_source(input_dir, &config), &config),
+        Action::Status { input_dir, json } => {
+            let path = year_file(&config.answers_file, year);
+            let known = if path.exists() {
+                KnownAnswers::load(&path)?
+            } else {
+                KnownAnswers::default()
+            };
+
+            let days = status::collect(year, &dir_source(input_dir, &config), &known, |day| {
+                config_params(&config, day)
+            });
+            if json {
+                println!("{}", serde_json::to_string_pretty(&days)?);
+            } else {
+                print!("{}", status::render(year, &days));
             }
+            Ok(())
+        }
+        Action::Bench {
+            day,
+            warmup,
+            runs,
+            time,
+            input,
+        } => {
+            let limit = match time {
+                Some(budget) => Limit::Budget(budget),
+                None => Limit::Runs(runs),
+            };
 
-            println!("Solved all in {:>5}", format!("{:.02?}", now.elapsed()));
+            let source = input.source(&config);
+            let days = match day {
+                Some(day) => vec![day],
+                None => days(year),
+            };
+
+            if days.len() > 1 && !source.is_per_day() {
+                anyhow::bail!("A single input can only be benchmarked against one day");
+            }
+
+            for day in days {
+                bench_day(
+                    year,
+                    day,
+                    warmup,
+                    limit,
+                    &source,
+                    &config_params(&config, day),
+                )?;
+            }
 
             Ok(())
         }
     }
 }
 
-fn solve_day(day: u32) -> Result<(), anyhow::Error> {
-    let input = std::fs::read_to_string(format!("./inputs/{day:02}.txt"))
-        .with_context(|| format!("Unable to find input for day {day}"))?;
-    let (part1, part2) = solution_for(day)?;
+#[allow(clippy::too_many_arguments)]
+fn run(
+    year: u32,
+    guesses_file: &Path,
+    selection: &DaySelection,
+    part: Option<u32>,
+    format: Format,
+    source: &InputSource,
+    limits: Option<Limits>,
+    params: impl Fn(u32) -> toml::Table,
+) -> Result<(), anyhow::Error> {
+    let days = selection.resolve(&days(year));
+    if days.len() > 1 && !source.is_per_day() {
+        anyhow::bail!("A single input can only be used with one day");
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let text = format == Format::Text;
+    let multiple = days.len() > 1;
+    let now = Instant::now();
+    if text && multiple {
+        println!("Solving {} days", days.len());
+    }
+
+    let exe = std::env::current_exe()?;
+    let journal = Journal::load(year_file(guesses_file, year))?;
+    let mut records = vec![];
+    for day in days {
+        let params = params(day);
+        let run = match limits {
+            Some(limits) => run_day_supervised(&exe, year, day, &parts, source, &params, limits),
+            None => run_day(year, day, &parts, source, &params),
+        };
+        if text && multiple {
+            println!("Solving day {day}");
+        }
+        if text {
+            print_day(&run);
+        }
+        if text && multiple {
+            println!("--------------------");
+        }
+
+        // Stderr keeps the json and csv output parseable.
+        for record in &run.records {
+            if let Some(warning) = record
+                .answer
+                .as_ref()
+                .and_then(|answer| journal.check(day, record.part, answer))
+            {
+                eprintln!("Warning: day {day} part {}: {warning}", record.part);
+            }
+        }
+
+        records.extend(run.records);
+    }
+
+    if text && multiple {
+        println!("Solved all in {:>5}", format!("{:.02?}", now.elapsed()));
+        print_summary(&records);
+    } else if !text {
+        print_records(&records, format)?;
+    }
+
+    // Batch runs skip days whose input hasn't been downloaded yet.
+    let failed = records
+        .iter()
+        .filter(|r| match r.status {
+            Status::Ok => false,
+            Status::MissingInput => !multiple,
+            _ => true,
+        })
+        .count();
+    if failed > 0 {
+        anyhow::bail!("{failed} part(s) failed");
+    }
+
+    Ok(())
+}
+
+// Answers and guesses for other years sit next to this year's, e.g.
+// `answers-2024.txt`.
+fn year_file(path: &Path, year: u32) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        return path.to_path_buf();
+    }
+
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    match path.extension() {
+        Some(ext) => path.with_file_name(format!("{stem}-{year}.{}", ext.to_string_lossy())),
+        None => path.with_file_name(format!("{stem}-{year}")),
+    }
+}
+
+fn config_params(config: &Config, day: u32) -> toml::Table {
+    config.params(day).cloned().unwrap_or_default()
+}
+
+fn dir_source(dir: Option<PathBuf>, config: &Config) -> InputSource {
+    InputSource::Dir(dir.unwrap_or_else(|| config.inputs_dir.clone()))
+}
+
+fn print_day(run: &DayRun) {
+    match (run.parse, run.records.first()) {
+        (Some(parse), _) => println!("Parsed in {:>10}", format!("{parse:.02?}")),
+        // The day failed before reaching the solver, e.g. a missing input.
+        (None, Some(first)) if run.records.iter().all(|r| r.message == first.message) => {
+            if let Some(message) = &first.message {
+                println!("{message}");
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    for record in &run.records {
+        match (&record.status, &record.answer) {
+            (Status::Ok, Some(answer)) => print_answer(record.part, answer),
+            _ => println!(
+                "Part {} failed: {}",
+                record.part,
+                record.message.as_deref().unwrap_or(record.status.as_str())
+            ),
+        }
+    }
+
+    let solved: Duration = run.records.iter().map(|r| r.duration).sum();
+    println!("Solved in {:>10}", format!("{solved:.02?}"));
+}
+
+fn print_summary(records: &[Record]) {
+    println!();
+    println!(
+        "{:>3}  {:>4}  {:<14}{:>10}  Result",
+        "Day", "Part", "Status", "Time"
+    );
+    for record in records {
+        let result = match (&record.answer, &record.message) {
+            (Some(answer), _) => answer.to_string(),
+            (None, Some(message)) => message.lines().map(str::trim).collect::<Vec<_>>().join(" "),
+            (None, None) => String::new(),
+        };
+        let time = match record.status {
+            Status::Ok => format!("{:.02?}", record.duration),
+            _ => String::new(),
+        };
+
+        println!(
+            "{:>3}  {:>4}  {:<14}{time:>10}  {result}",
+            record.day,
+            record.part,
+            record.status.as_str(),
+        );
+    }
+
+    let count = |status| records.iter().filter(|r| r.status == status).count();
+    println!(
+        "{} ok, {} failed, {} missing input",
+        count(Status::Ok),
+        records.len() - count(Status::Ok) - count(Status::MissingInput),
+        count(Status::MissingInput)
+    );
+}
+
+fn print_records(records: &[Record], format: Format) -> Result<(), anyhow::Error> {
+    match format {
+        Format::Json => println!("{}", report::to_json(records)?),
+        Format::Csv => print!("{}", report::to_csv(records)),
+        Format::Text => records.iter().for_each(|r| match &r.answer {
+            Some(answer) => print_answer(r.part, answer),
+            None => println!("Part {}: {}", r.part, r.status.as_str()),
+        }),
+    }
+
+    Ok(())
+}
+
+fn verify(year: u32, source: &InputSource, config: &Config) -> Result<(), anyhow::Error> {
+    let known = KnownAnswers::load(year_file(&config.answers_file, year))?;
+    let mut failures = 0;
+
+    for day in days(year) {
+        let run = run_day(year, day, &[1, 2], source, &config_params(config, day));
+
+        for record in run.records {
+            let part = record.part;
+            let status = match (record.status, record.answer, known.get(day, part)) {
+                (Status::MissingInput, ..) => "missing input".to_string(),
+                (Status::Ok, Some(answer), Some(expected)) if answer == *expected => {
+                    format!("pass {answer}")
+                }
+                (Status::Ok, Some(answer), Some(expected)) => {
+                    failures += 1;
+                    format!("FAIL got {answer}, expected {expected}")
+                }
+                (Status::Ok, Some(answer), None) if !answer.is_solved() => {
+                    "skipped (unsolved)".to_string()
+                }
+                (Status::Ok, Some(answer), None) => format!("missing answer, got {answer}"),
+                (status, ..) => {
+                    failures += 1;
+                    let message = record.message.unwrap_or_default();
+                    format!("FAIL {}: {message}", status.as_str())
+                }
+            };
+
+            println!("Day {day:>2} part {part}: {status}");
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{failures} part(s) failed verification");
+    }
+
+    Ok(())
+}
+
+fn check_samples(year: u32, day: u32, config: &Config) -> Result<(), anyhow::Error> {
+    let manifest = Manifest::load(&config.samples_for(year))?;
+    let mut checked = 0;
+    let mut failures = 0;
+
+    for sample in manifest.for_day(day) {
+        for check in samples::check(year, &manifest.dir, sample) {
+            checked += 1;
+            if !check.passed() {
+                failures += 1;
+            }
+            println!(
+                "{} part {}: {}",
+                check.file.display(),
+                check.record.part,
+                check.describe()
+            );
+        }
+    }
+
+    if checked == 0 {
+        anyhow::bail!(
+            "No sample answers listed for day {day} in {}",
+            manifest.dir.join(samples::MANIFEST).display()
+        );
+    }
+    if failures > 0 {
+        anyhow::bail!("{failures} sample part(s) failed");
+    }
+
+    Ok(())
+}
+
+fn submit(
+    year: u32,
+    day: u32,
+    part: u32,
+    base_url: &str,
+    force: bool,
+    source: &InputSource,
+    config: &Config,
+) -> Result<(), anyhow::Error> {
+    let run = run_day(year, day, &[part], source, &config_params(config, day));
+    let record = &run.records[0];
+    let answer = match (&record.status, &record.answer) {
+        (Status::Ok, Some(answer)) if answer.is_solved() => answer,
+        (Status::Ok, _) => anyhow::bail!("Day {day} part {part} is unsolved, nothing to submit"),
+        (status, _) => anyhow::bail!(
+            "Day {day} part {part} failed: {}",
+            record.message.as_deref().unwrap_or(status.as_str())
+        ),
+    };
 
-    let now = std::time::Instant::now();
-    let r1 = part1(&input)?;
-    println!("Part 1: {r1}");
+    let path = year_file(&config.guesses_file, year);
+    let mut journal = Journal::load(&path)?;
+    if let Some(warning) = journal.check(day, part, answer) {
+        if !force {
+            anyhow::bail!("Not submitting {answer}: {warning}, use --force to submit anyway");
+        }
+        eprintln!("Warning: {answer}: {warning}");
+    }
 
-    let r2 = part2(&input)?;
-    println!("Part 2: {r2}");
+    println!("Submitting {answer} for day {day} part {part}");
+    let client = Client::new(
+        base_url,
+        &client::session_token(config.session_file.as_deref(), &config.root)?,
+    );
+    let verdict = client.submit(year, day, part, answer)?;
+    if let Some(outcome) = Outcome::from_verdict(&verdict) {
+        journal.record(
+            &path,
+            Guess {
+                day,
+                part,
+                answer: answer.clone(),
+                outcome,
+            },
+        )?;
+    }
 
-    println!("Solved in {:?}", now.elapsed());
+    match verdict {
+        Verdict::Correct => println!("Correct!"),
+        Verdict::Incorrect { hint } => {
+            let hint = match hint {
+                Some(Hint::TooHigh) => ", too high",
+                Some(Hint::TooLow) => ", too low",
+                None => "",
+            };
+            anyhow::bail!("{answer} is incorrect{hint}");
+        }
+        Verdict::RateLimited { wait } => match wait {
+            Some(wait) => anyhow::bail!(
+                "Submitted too recently, try again in {}",
+                humantime::format_duration(wait)
+            ),
+            None => anyhow::bail!("Submitted too recently, try again later"),
+        },
+        Verdict::AlreadySolved => println!("Day {day} part {part} is already solved"),
+    }
 
     Ok(())
 }
+
+fn bench_day(
+    year: u32,
+    day: u32,
+    warmup: usize,
+    limit: Limit,
+    source: &InputSource,
+    params: &toml::Table,
+) -> Result<(), anyhow::Error> {
+    let input = source.load(year, day)?;
+    let solution = solution_for(year, day)?;
+
+    let parse = bench::measure(warmup, limit, || solution(&input, params))?;
+    let parsed = solution(&input, params)?;
+    let part1 = bench::measure(warmup, limit, || parsed.part1())?;
+    let part2 = bench::measure(warmup, limit, || parsed.part2())?;
+
+    println!("Day {day}");
+    println!(
+        "{:<8}{:>8}{:>12}{:>12}{:>12}{:>12}{:>12}",
+        "", "runs", "min", "median", "mean", "p95", "std dev"
+    );
+    print_stats("parse", &parse);
+    print_stats("part 1", &part1);
+    print_stats("part 2", &part2);
+    println!("--------------------");
+
+    Ok(())
+}
+
+fn print_stats(label: &str, stats: &Stats) {
+    let columns = [
+        stats.min,
+        stats.median,
+        stats.mean,
+        stats.p95,
+        stats.std_dev,
+    ]
+    .map(|d| format!("{:>12}", format!("{d:.02?}")));
+    println!("{label:<8}{:>8}{}", stats.runs, columns.join(""));
+}
+
+fn print_answer(part: u32, answer: &Answer) {
+    if answer.is_solved() {
+        println!("Part {part}: {answer}");
+    }
+}
@@ -0,0 +1,67 @@
+use crate::AocError;
+
+// Parses `name=value`, reading the value as TOML so `10` is an integer and
+// `true` a boolean, and falling back to a plain string otherwise.
+pub fn parse_assignment(s: &str) -> Result<(String, toml::Value), AocError> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| AocError::InvalidArgument(format!("Expected name=value, got {s:?}")))?;
+
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AocError::InvalidArgument(format!(
+            "Missing parameter name in {s:?}"
+        )));
+    }
+
+    let value = format!("value = {}", value.trim())
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.trim().to_string()));
+
+    Ok((name.to_string(), value))
+}
+
+// Applies `overrides` on top of the parameters from the config file.
+pub fn merge(base: Option<&toml::Table>, overrides: &[(String, toml::Value)]) -> toml::Table {
+    let mut params = base.cloned().unwrap_or_default();
+    for (name, value) in overrides {
+        params.insert(name.clone(), value.clone());
+    }
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_assignment_test() -> Result<(), AocError> {
+        assert_eq!(
+            parse_assignment("connections=10")?,
+            ("connections".to_string(), toml::Value::Integer(10))
+        );
+        assert_eq!(
+            parse_assignment(" fast = true ")?,
+            ("fast".to_string(), toml::Value::Boolean(true))
+        );
+        assert_eq!(
+            parse_assignment("start=you")?,
+            ("start".to_string(), toml::Value::String("you".to_string()))
+        );
+        assert!(parse_assignment("connections").is_err());
+        assert!(parse_assignment("=10").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn merge_test() -> Result<(), AocError> {
+        let base: toml::Table = "dial = 100\nstart = 50".parse()?;
+        let merged = merge(Some(&base), &[parse_assignment("start=0")?]);
+
+        assert_eq!(merged["dial"], toml::Value::Integer(100));
+        assert_eq!(merged["start"], toml::Value::Integer(0));
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{Answer, AocError, runner::Record};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+pub fn to_json(records: &[Record]) -> Result<String, AocError> {
+    Ok(serde_json::to_string_pretty(records)?)
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut out = String::from("day,part,answer,duration_ns,status,message\n");
+
+    for record in records {
+        // Left empty when unsolved, matching the `null` in the JSON output.
+        let answer = record
+            .answer
+            .as_ref()
+            .filter(|a| !matches!(a, Answer::Unsolved))
+            .map(|a| a.to_string())
+            .unwrap_or_default();
+
+        let fields = [
+            record.day.to_string(),
+            record.part.to_string(),
+            answer,
+            record.duration.as_nanos().to_string(),
+            record.status.as_str().to_string(),
+            record.message.clone().unwrap_or_default(),
+        ];
+
+        let line: Vec<_> = fields.iter().map(|f| escape_csv(f)).collect();
+        out.push_str(&line.join(","));
+        out.push('\n');
+    }
+
+    out
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Status;
+    use std::time::Duration;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                part: 1,
+                answer: Some(Answer::Unsigned(3)),
+                duration: Duration::from_micros(5),
+                status: Status::Ok,
+                message: None,
+            },
+            Record {
+                day: 2,
+                part: 2,
+                answer: None,
+                duration: Duration::ZERO,
+                status: Status::MissingInput,
+                message: Some("Unable to read \"02.txt\", not found".to_string()),
+            },
+            Record {
+                day: 12,
+                part: 2,
+                answer: Some(Answer::Unsolved),
+                duration: Duration::ZERO,
+                status: Status::Ok,
+                message: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn csv_test() {
+        assert_eq!(
+            to_csv(&records()),
+            "day,part,answer,duration_ns,status,message
+1,1,3,5000,ok,
+2,2,,0,missing_input,\"Unable to read \"\"02.txt\"\", not found\"
+12,2,,0,ok,
+"
+        );
+    }
+
+    #[test]
+    fn json_test() -> Result<(), AocError> {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records())?)?;
+
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"day": 1, "part": 1, "answer": 3, "duration_ns": 5000, "status": "ok"},
+                {
+                    "day": 2,
+                    "part": 2,
+                    "answer": null,
+                    "duration_ns": 0,
+                    "status": "missing_input",
+                    "message": "Unable to read \"02.txt\", not found"
+                },
+                {"day": 12, "part": 2, "answer": null, "duration_ns": 0, "status": "ok"}
+            ])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{Answer, AocError, input::InputSource, solution_for};
+use serde::{Deserialize, Serialize, Serializer};
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Error,
+    Panicked,
+    TimedOut,
+    OutOfMemory,
+    // The worker process died from a signal, e.g. a stack overflow.
+    Crashed,
+    MissingInput,
+    NoSolution,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+            Status::OutOfMemory => "out_of_memory",
+            Status::Crashed => "crashed",
+            Status::MissingInput => "missing_input",
+            Status::NoSolution => "no_solution",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    #[serde(serialize_with = "plain_answer")]
+    pub answer: Option<Answer>,
+    #[serde(rename = "duration_ns", serialize_with = "as_nanos")]
+    pub duration: Duration,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl Record {
+    pub(crate) fn failed(day: u32, part: u32, status: Status, message: String) -> Self {
+        Record {
+            day,
+            part,
+            answer: None,
+            duration: Duration::ZERO,
+            status,
+            message: Some(message),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub year: u32,
+    pub day: u32,
+    pub parse: Option<Duration>,
+    pub records: Vec<Record>,
+}
+
+pub fn run_day(
+    year: u32,
+    day: u32,
+    parts: &[u32],
+    source: &InputSource,
+    params: &toml::Table,
+) -> DayRun {
+    let failed = |status, message: String| DayRun {
+        year,
+        day,
+        parse: None,
+        records: parts
+            .iter()
+            .map(|&part| Record::failed(day, part, status, message.clone()))
+            .collect(),
+    };
+
+    let solution = match solution_for(year, day) {
+        Ok(solution) => solution,
+        Err(e) => return failed(Status::NoSolution, e.to_string()),
+    };
+
+    let input = match source.load(year, day) {
+        Ok(input) => input,
+        Err(e @ AocError::MissingInput { .. }) => {
+            return failed(Status::MissingInput, e.to_string());
+        }
+        Err(e) => return failed(Status::Error, e.to_string()),
+    };
+
+    let now = Instant::now();
+    let parsed = match catch(|| solution(&input, params)) {
+        Ok(parsed) => parsed,
+        Err((status, e)) => return failed(status, format!("Failed to parse input: {e}")),
+    };
+    let parse = now.elapsed();
+
+    let records = parts
+        .iter()
+        .map(|&part| {
+            let now = Instant::now();
+            let result = catch(|| {
+                if part == 1 {
+                    parsed.part1()
+                } else {
+                    parsed.part2()
+                }
+            });
+            let duration = now.elapsed();
+
+            match result {
+                Ok(answer) => Record {
+                    day,
+                    part,
+                    answer: Some(answer),
+                    duration,
+                    status: Status::Ok,
+                    message: None,
+                },
+                Err((status, e)) => Record {
+                    duration,
+                    ..Record::failed(day, part, status, e)
+                },
+            }
+        })
+        .collect();
+
+    DayRun {
+        year,
+        day,
+        parse: Some(parse),
+        records,
+    }
+}
+
+pub(crate) fn catch<T>(f: impl FnOnce() -> Result<T, AocError>) -> Result<T, (Status, String)> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err((Status::Error, format!("{e:#}"))),
+        Err(payload) => Err((Status::Panicked, panic_message(payload.as_ref()))),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        format!("panicked: {s}")
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        format!("panicked: {s}")
+    } else {
+        "panicked".to_string()
+    }
+}
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+fn plain_answer<S: Serializer>(answer: &Option<Answer>, serializer: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        Some(Answer::Signed(n)) => serializer.serialize_i64(*n),
+        Some(Answer::Unsigned(n)) => serializer.serialize_u64(*n),
+        Some(Answer::Big(n)) => serializer.collect_str(n),
+        Some(Answer::Text(s)) => serializer.serialize_str(s),
+        Some(Answer::Unsolved) | None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_YEAR;
+
+    #[test]
+    fn catches_panics() {
+        let result = catch::<()>(|| panic!("index out of bounds"));
+        assert_eq!(
+            result,
+            Err((
+                Status::Panicked,
+                "panicked: index out of bounds".to_string()
+            ))
+        );
+
+        let result = catch::<()>(|| Err(AocError::Unsolvable("no path from you to out".into())));
+        assert_eq!(
+            result,
+            Err((Status::Error, "no path from you to out".to_string()))
+        );
+    }
+
+    #[test]
+    fn keeps_going_after_failures() {
+        let run = run_day(
+            DEFAULT_YEAR,
+            11,
+            &[1, 2],
+            &InputSource::Inline("svr: out".to_string()),
+            &toml::Table::new(),
+        );
+
+        assert_eq!(run.records.len(), 2);
+        assert!(run.records.iter().all(|r| r.status == Status::Error));
+        assert_eq!(
+            run.records[0].message.as_deref(),
+            Some("There is no device named you")
+        );
+    }
+
+    #[test]
+    fn unreadable_input_is_an_error() -> Result<(), AocError> {
+        let dir = std::env::temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("03.txt");
+        std::fs::write(&path, [0xff, 0xfe, b'\n'])?;
+
+        let run = run_day(
+            DEFAULT_YEAR,
+            3,
+            &[1],
+            &InputSource::Dir(dir.clone()),
+            &toml::Table::new(),
+        );
+        assert_eq!(run.records[0].status, Status::Error);
+
+        let run = run_day(
+            DEFAULT_YEAR,
+            3,
+            &[1],
+            &InputSource::File(dir.join("missing.txt")),
+            &toml::Table::new(),
+        );
+        assert_eq!(run.records[0].status, Status::MissingInput);
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{
+    Answer, AocError,
+    input::InputSource,
+    runner::{Record, Status, run_day},
+};
+use serde::{Deserialize, Deserializer};
+use std::path::{Path, PathBuf};
+
+// Lives next to the sample files it lists, one per samples directory.
+pub const MANIFEST: &str = "samples.toml";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Sample {
+    pub day: u32,
+    // Relative to the manifest.
+    pub file: PathBuf,
+    #[serde(default, deserialize_with = "expected")]
+    pub part1: Option<Answer>,
+    #[serde(default, deserialize_with = "expected")]
+    pub part2: Option<Answer>,
+    #[serde(default)]
+    pub params: toml::Table,
+}
+
+impl Sample {
+    pub fn expected(&self, part: u32) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn parts(&self) -> Vec<u32> {
+        [1, 2]
+            .into_iter()
+            .filter(|&part| self.expected(part).is_some())
+            .collect()
+    }
+}
+
+// Answers are written as integers where they fit and as strings otherwise.
+fn expected<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Answer>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Expected {
+        Integer(i64),
+        Text(String),
+    }
+
+    Ok(Some(match Expected::deserialize(deserializer)? {
+        Expected::Integer(n) => n.into(),
+        Expected::Text(s) => s.parse().unwrap_or_else(|e| match e {}),
+    }))
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    #[serde(default, rename = "sample")]
+    pub samples: Vec<Sample>,
+    #[serde(skip)]
+    pub dir: PathBuf,
+}
+
+impl Manifest {
+    // A samples directory without a manifest lists no samples.
+    pub fn load(dir: &Path) -> Result<Self, AocError> {
+        let path = dir.join(MANIFEST);
+        if !path.exists() {
+            return Ok(Manifest {
+                dir: dir.to_path_buf(),
+                ..Manifest::default()
+            });
+        }
+
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| AocError::io(format!("Unable to read {}", path.display()), e))?;
+        let mut manifest = Self::parse(&contents).map_err(|e| {
+            AocError::Config(format!("Invalid sample manifest {}: {e}", path.display()))
+        })?;
+        manifest.dir = dir.to_path_buf();
+        Ok(manifest)
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, AocError> {
+        let manifest: Manifest = toml::from_str(contents)?;
+
+        for sample in &manifest.samples {
+            if !(1..=25).contains(&sample.day) {
+                return Err(AocError::Config(format!(
+                    "Invalid day {} for {}",
+                    sample.day,
+                    sample.file.display()
+                )));
+            }
+        }
+
+        Ok(manifest)
+    }
+
+    pub fn for_day(&self, day: u32) -> impl Iterator<Item = &Sample> {
+        self.samples.iter().filter(move |s| s.day == day)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub file: PathBuf,
+    pub expected: Answer,
+    pub record: Record,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.record.status == Status::Ok && self.record.answer.as_ref() == Some(&self.expected)
+    }
+
+    pub fn describe(&self) -> String {
+        match (&self.record.status, &self.record.answer) {
+            _ if self.passed() => format!("pass {}", self.expected),
+            (Status::Ok, Some(answer)) => {
+                format!("FAIL got {answer}, expected {}", self.expected)
+            }
+            (status, _) => format!(
+                "FAIL {}: {}",
+                status.as_str(),
+                self.record.message.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+// Runs the parts of `sample` that have an expected answer, with the sample's
+// own parameters rather than any configured for the real input.
+pub fn check(year: u32, dir: &Path, sample: &Sample) -> Vec<Check> {
+    let source = InputSource::File(dir.join(&sample.file));
+    let run = run_day(year, sample.day, &sample.parts(), &source, &sample.params);
+
+    run.records
+        .into_iter()
+        .filter_map(|record| {
+            Some(Check {
+                file: sample.file.clone(),
+                expected: sample.expected(record.part)?.clone(),
+                record,
+            })
+        })
+        .collect()
+}
+
+// Called by the tests the build script generates from each manifest.
+#[cfg(test)]
+pub(crate) fn assert_sample(dir: &str, year: u32, day: u32, file: &str, part: u32) {
+    let dir = Path::new(dir);
+    let manifest = Manifest::load(dir).unwrap();
+    let sample = manifest
+        .for_day(day)
+        .find(|s| s.file == Path::new(file))
+        .unwrap_or_else(|| panic!("{file} is not listed for day {day}"));
+
+    let check = check(year, dir, sample)
+        .into_iter()
+        .find(|c| c.record.part == part)
+        .unwrap_or_else(|| panic!("{file} has no answer for part {part}"));
+    assert!(check.passed(), "{file} part {part}: {}", check.describe());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_YEAR;
+
+    #[test]
+    fn parse_test() -> Result<(), AocError> {
+        let manifest = Manifest::parse(
+            r#"
+[[sample]]
+day = 8
+file = "08.txt"
+part1 = 40
+part2 = "25272"
+params = { connections = 10 }
+
+[[sample]]
+day = 11
+file = "11.2.txt"
+part2 = 2
+"#,
+        )?;
+
+        let samples: Vec<_> = manifest.for_day(8).collect();
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].expected(1), Some(&Answer::Unsigned(40)));
+        assert_eq!(samples[0].expected(2), Some(&Answer::Unsigned(25272)));
+        assert_eq!(samples[0].params["connections"], toml::Value::Integer(10));
+        assert_eq!(manifest.for_day(11).next().unwrap().parts(), [2]);
+
+        assert!(Manifest::parse("[[sample]]\nday = 26\nfile = \"26.txt\"").is_err());
+        assert!(Manifest::parse("[[sample]]\nday = 1\nfile = \"01.txt\"\npart3 = 1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_test() -> Result<(), AocError> {
+        let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/samples"));
+        let sample = Sample {
+            day: 8,
+            file: PathBuf::from("08.txt"),
+            part1: Some(Answer::Unsigned(41)),
+            part2: None,
+            params: "connections = 10".parse()?,
+        };
+
+        let checks = check(DEFAULT_YEAR, dir, &sample);
+        assert_eq!(checks.len(), 1);
+        assert!(!checks[0].passed());
+        assert_eq!(checks[0].describe(), "FAIL got 40, expected 41");
+        Ok(())
+    }
+
+    // Every sample must give the same answers however its line endings and
+    // final newline were saved.
+    #[test]
+    fn answers_survive_line_ending_variants() -> Result<(), AocError> {
+        let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/samples"));
+        let manifest = Manifest::load(dir)?;
+        assert!(!manifest.samples.is_empty());
+
+        // Freshly scaffolded days list a sample with no answers and no input yet.
+        for sample in manifest.samples.iter().filter(|s| !s.parts().is_empty()) {
+            let contents = std::fs::read_to_string(dir.join(&sample.file))?;
+            let bare = contents.trim_end_matches('\n');
+            let variants = [
+                ("no trailing newline", bare.to_string()),
+                ("trailing newline", format!("{bare}\n")),
+                ("blank lines at the end", format!("{bare}\n\n\n")),
+                ("CRLF", format!("{bare}\n").replace('\n', "\r\n")),
+                ("CRLF without trailing newline", bare.replace('\n', "\r\n")),
+                ("BOM", format!("\u{feff}{bare}\n")),
+            ];
+
+            let solution = crate::solution_for(DEFAULT_YEAR, sample.day)?;
+            for (variant, input) in variants {
+                let parsed = solution(&input, &sample.params)
+                    .unwrap_or_else(|e| panic!("{} ({variant}): {e}", sample.file.display()));
+                for part in sample.parts() {
+                    let answer = if part == 1 {
+                        parsed.part1()?
+                    } else {
+                        parsed.part2()?
+                    };
+                    assert_eq!(
+                        Some(&answer),
+                        sample.expected(part),
+                        "{} part {part} ({variant})",
+                        sample.file.display()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{AocError, DEFAULT_YEAR, config::Config, samples};
+use clap::ValueEnum;
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Template {
+    /// One `String` per input line
+    #[default]
+    Lines,
+    /// A chumsky parser over the whole input
+    Chumsky,
+    /// A `Grid<char>` of the input
+    Grid,
+}
+
+impl Template {
+    fn source(self) -> &'static str {
+        match self {
+            Template::Lines => include_str!("../templates/lines.rs.tmpl"),
+            Template::Chumsky => include_str!("../templates/chumsky.rs.tmpl"),
+            Template::Grid => include_str!("../templates/grid.rs.tmpl"),
+        }
+    }
+
+    pub fn render(self, year: u32, day: u32) -> String {
+        let registration = if year == DEFAULT_YEAR {
+            format!("day = {day}")
+        } else {
+            format!("year = {year}, day = {day}")
+        };
+        self.source()
+            .replace("{{registration}}", &registration)
+            .replace("{{padded}}", &format!("{day:02}"))
+            .replace("{{day}}", &day.to_string())
+    }
+}
+
+// The module for `day`, relative to the repository root. Earlier years get a
+// directory of their own.
+pub fn module_path(year: u32, day: u32) -> PathBuf {
+    let file = format!("day{day:02}.rs");
+    if year == DEFAULT_YEAR {
+        Path::new("src/solutions").join(file)
+    } else {
+        Path::new("src/solutions").join(year.to_string()).join(file)
+    }
+}
+
+// The path of the module within the crate, as used by `cargo test` filters.
+pub fn module_name(year: u32, day: u32) -> String {
+    if year == DEFAULT_YEAR {
+        format!("solutions::day{day:02}")
+    } else {
+        format!("solutions::y{year}::day{day:02}")
+    }
+}
+
+// Writes the module and an empty `NN.txt` in the samples directory, and lists
+// the sample in the manifest with its answers left to fill in. The `#[aoc]`
+// attribute and build script take care of registering the day.
+pub fn create(
+    config: &Config,
+    year: u32,
+    day: u32,
+    template: Template,
+) -> Result<Vec<PathBuf>, AocError> {
+    let solutions = config.root.join("src/solutions");
+    if !solutions.is_dir() {
+        return Err(AocError::InvalidArgument(format!(
+            "{} does not exist, run this from the repository root",
+            solutions.display()
+        )));
+    }
+
+    let module = config.root.join(module_path(year, day));
+    let file = format!("{day:02}.txt");
+    let sample = config.samples_for(year).join(&file);
+    if let Some(existing) = [&module, &sample].into_iter().find(|p| p.exists()) {
+        return Err(AocError::InvalidArgument(format!(
+            "{} already exists, not overwriting it",
+            existing.display()
+        )));
+    }
+
+    for path in [&module, &sample] {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| AocError::io(format!("Unable to create {}", dir.display()), e))?;
+        }
+    }
+    std::fs::write(&module, template.render(year, day))
+        .map_err(|e| AocError::io(format!("Unable to write {}", module.display()), e))?;
+    std::fs::write(&sample, "")
+        .map_err(|e| AocError::io(format!("Unable to write {}", sample.display()), e))?;
+
+    let manifest = config.samples_for(year).join(samples::MANIFEST);
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&manifest)
+        .and_then(|mut f| {
+            writeln!(
+                f,
+                "\n[[sample]]\nday = {day}\nfile = \"{file}\"\n# part1 =\n# part2 ="
+            )
+        })
+        .map_err(|e| AocError::io(format!("Unable to update {}", manifest.display()), e))?;
+
+    Ok(vec![module, sample])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        for template in Template::value_variants() {
+            let module = template.render(DEFAULT_YEAR, 7);
+            assert!(module.contains("pub struct Day07;"));
+            assert!(module.contains("#[aoc(day = 7)]"));
+            assert!(!module.contains("{{"), "{module}");
+        }
+    }
+
+    #[test]
+    fn refuses_to_overwrite() -> Result<(), AocError> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src/solutions"))?;
+
+        let config = Config {
+            samples_dir: root.join("examples"),
+            root: root.clone(),
+            ..Config::default()
+        };
+
+        let created = create(&config, DEFAULT_YEAR, 13, Template::Grid)?;
+        assert_eq!(
+            created,
+            [
+                root.join("src/solutions/day13.rs"),
+                root.join("examples/13.txt")
+            ]
+        );
+        let manifest = samples::Manifest::load(&root.join("examples"))?;
+        let sample = manifest.for_day(13).next().unwrap();
+        assert_eq!(sample.file, Path::new("13.txt"));
+        assert_eq!(sample.parts(), [] as [u32; 0]);
+        assert!(create(&config, DEFAULT_YEAR, 13, Template::Lines).is_err());
+
+        std::fs::remove_file(root.join("src/solutions/day13.rs"))?;
+        let error = create(&config, DEFAULT_YEAR, 13, Template::Lines).unwrap_err();
+        assert!(error.to_string().contains("13.txt already exists"));
+
+        let created = create(&config, 2024, 13, Template::Lines)?;
+        assert_eq!(
+            created,
+            [
+                root.join("src/solutions/2024/day13.rs"),
+                root.join("examples/2024/13.txt")
+            ]
+        );
+        let module = std::fs::read_to_string(&created[0])?;
+        assert!(module.contains("#[aoc(year = 2024, day = 13)]"));
+        let manifest = samples::Manifest::load(&root.join("examples/2024"))?;
+        assert_eq!(manifest.for_day(13).count(), 1);
+
+        std::fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use crate::AocError;
+use std::{ops::RangeInclusive, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Day(u32),
+    Range(RangeInclusive<u32>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(Vec<Item>);
+
+impl DaySelection {
+    pub fn all() -> Self {
+        DaySelection(vec![Item::Range(1..=25)])
+    }
+
+    // Days named explicitly are always kept so that an unregistered day is
+    // reported, while ranges only pick up days that have a solution.
+    pub fn resolve(&self, registered: &[u32]) -> Vec<u32> {
+        let mut days: Vec<u32> = self
+            .0
+            .iter()
+            .flat_map(|item| match item {
+                Item::Day(day) => vec![*day],
+                Item::Range(range) => range
+                    .clone()
+                    .filter(|day| registered.contains(day))
+                    .collect(),
+            })
+            .collect();
+
+        days.sort();
+        days.dedup();
+        days
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |s: &str| -> Result<u32, AocError> {
+            let day = s
+                .trim()
+                .parse::<u32>()
+                .map_err(|e| AocError::InvalidArgument(format!("Invalid day {s:?}: {e}")))?;
+            if !(1..=25).contains(&day) {
+                return Err(AocError::InvalidArgument(format!(
+                    "Day {day} is outside 1..=25"
+                )));
+            }
+            Ok(day)
+        };
+
+        let items = s
+            .split(',')
+            .map(|item| {
+                if let Some((start, end)) = item.split_once("..=") {
+                    Ok(Item::Range(day(start)?..=day(end)?))
+                } else if let Some((start, end)) = item.split_once("..") {
+                    let end = day(end)?.checked_sub(1).ok_or_else(|| {
+                        AocError::InvalidArgument(format!("Empty range {item:?}"))
+                    })?;
+                    Ok(Item::Range(day(start)?..=end))
+                } else {
+                    day(item).map(Item::Day)
+                }
+            })
+            .collect::<Result<Vec<_>, AocError>>()?;
+
+        if items
+            .iter()
+            .any(|item| matches!(item, Item::Range(r) if r.is_empty()))
+        {
+            return Err(AocError::InvalidArgument(format!(
+                "Empty day range in {s:?}"
+            )));
+        }
+
+        Ok(DaySelection(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTERED: &[u32] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    #[test]
+    fn parse_test() -> Result<(), AocError> {
+        let resolve = |s: &str| -> Result<Vec<u32>, AocError> {
+            Ok(s.parse::<DaySelection>()?.resolve(REGISTERED))
+        };
+
+        assert_eq!(resolve("4")?, vec![4]);
+        assert_eq!(resolve("3..=7")?, vec![3, 4, 5, 6, 7]);
+        assert_eq!(resolve("3..7")?, vec![3, 4, 5, 6]);
+        assert_eq!(resolve("9,1,4,1")?, vec![1, 4, 9]);
+        assert_eq!(resolve("10..=25,20")?, vec![10, 11, 12, 20]);
+        assert_eq!(DaySelection::all().resolve(REGISTERED), REGISTERED);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_days() {
+        for s in ["0", "26", "x", "7..=3", "1..1", "", "1,,2"] {
+            assert!(s.parse::<DaySelection>().is_err(), "{s}");
+        }
+    }
+}
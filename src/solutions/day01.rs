@@ -1,82 +1,80 @@
-use anyhow::anyhow;
+use crate::{AocError, Answer, Solver, aoc};
+use crate::utils::{
+    diagnostics::Extra,
+    parse::{self, lines, unsigned},
+};
 use chumsky::prelude::*;
+use serde::Deserialize;
 
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let nums = directions()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow!("Failed to parse input {e:?}"))?;
+pub struct Day01;
 
-    let (_, count) = nums.iter().fold((50, 0), |(pos, count), amt| {
-        let next = (pos + amt).rem_euclid(100);
-        (next, count + if next == 0 { 1 } else { 0 })
-    });
+#[aoc(day = 1)]
+impl Solver for Day01 {
+    type Input = Vec<i32>;
+    type Params = Params;
 
-    Ok(count.to_string())
-}
-
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let nums = directions()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow!("Failed to parse input {e:?}"))?;
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::parse_all(1, directions(), input)
+    }
 
-    let (_, count) = nums.iter().fold((50, 0), |(pos, count), amt| {
-        let total = pos + amt;
-        let revolutions = (pos + amt).abs() / 100;
+    fn part1(nums: &Self::Input, params: &Params) -> Result<Answer, AocError> {
+        let (dial, start) = params.dial()?;
+        let (_, count) = nums.iter().fold((start, 0), |(pos, count), &amt| {
+            let next = (pos + amt as i64).rem_euclid(dial);
+            (next, count + if next == 0 { 1 } else { 0 })
+        });
 
-        (
-            total.rem_euclid(100),
-            count + revolutions + if pos != 0 && total <= 0 { 1 } else { 0 },
-        )
-    });
+        Ok(count.into())
+    }
 
-    Ok(count.to_string())
-}
+    fn part2(nums: &Self::Input, params: &Params) -> Result<Answer, AocError> {
+        let (dial, start) = params.dial()?;
+        let (_, count) = nums.iter().fold((start, 0), |(pos, count), &amt| {
+            let total = pos + amt as i64;
+            let revolutions = total.abs() / dial;
 
-fn directions<'src>() -> impl Parser<'src, &'src str, Vec<i32>> {
-    let int32 = text::int(10).map(|v: &str| v.parse::<i32>().unwrap());
-    let left = just('L').ignore_then(int32).map(|v| -v);
-    let right = just('R').ignore_then(int32);
+            (
+                total.rem_euclid(dial),
+                count + revolutions + if pos != 0 && total <= 0 { 1 } else { 0 },
+            )
+        });
 
-    left.or(right).separated_by(text::newline()).collect()
+        Ok(count.into())
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    dial: i32,
+    start: i32,
+}
 
-    #[test]
-    fn part1_test() -> anyhow::Result<()> {
-        let input = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
+impl Params {
+    // Widened so that a turn can't overflow the position.
+    fn dial(&self) -> Result<(i64, i64), AocError> {
+        if self.dial <= 0 {
+            return Err(AocError::InvalidParams(format!(
+                "dial must be positive, got {}",
+                self.dial
+            )));
+        }
+        Ok((self.dial as i64, self.start.rem_euclid(self.dial) as i64))
+    }
+}
 
-        assert_eq!(part1(input)?, "3".to_string());
-        Ok(())
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            dial: 100,
+            start: 50,
+        }
     }
+}
 
-    #[test]
-    fn part2_test() -> anyhow::Result<()> {
-        let input = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
+fn directions<'src>() -> impl Parser<'src, &'src str, Vec<i32>, Extra<'src>> {
+    let left = just('L').ignore_then(unsigned::<i32>()).map(|v| -v);
+    let right = just('R').ignore_then(unsigned());
 
-        assert_eq!(part2(input)?, "6".to_string());
-        Ok(())
-    }
+    lines(left.or(right))
 }
@@ -1,60 +1,79 @@
-use anyhow::anyhow;
+use crate::{AocError, Answer, NoParams, Solver, aoc};
+use crate::utils::{
+    diagnostics::Extra,
+    parse::{self, range_inclusive, unsigned},
+};
 use chumsky::prelude::*;
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::ops::RangeInclusive;
 
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let ranges = ranges()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow!("Failed to parse input {e:?}"))?;
-
-    let ranges = ranges
-        .into_par_iter()
-        .map(|r| r.filter(|&v| is_invalid_id(v)).sum::<u64>())
-        .sum::<u64>();
-
-    fn is_invalid_id(n: u64) -> bool {
-        let length = n.ilog10() + 1;
-        if !length.is_multiple_of(2) {
-            return false;
-        }
+pub struct Day02;
+
+#[aoc(day = 2)]
+impl Solver for Day02 {
+    type Input = Vec<RangeInclusive<u64>>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::parse_all(2, ranges(), input)
+    }
+
+    fn part1(ranges: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+
+        fn is_invalid_id(n: u64) -> bool {
+            let length = digits(n);
+            if !length.is_multiple_of(2) {
+                return false;
+            }
+
+            let divisor = 10u64.pow(length / 2);
 
-        let divisor = 10u64.pow(length / 2);
+            let first = n / divisor;
+            let rest = n % divisor;
 
-        let first = n / divisor;
-        let rest = n % divisor;
+            first == rest
+        }
 
-        first == rest
+        sum_invalid_ids(ranges, is_invalid_id)
     }
 
-    Ok(ranges.to_string())
-}
+    fn part2(ranges: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+
+        fn is_invalid_id(n: u64) -> bool {
+            let length = digits(n);
+            (1..=(length / 2))
+                .any(|size| length.is_multiple_of(size) && chunk_number(n, size).all_equal())
+        }
 
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let ranges = ranges()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow!("Failed to parse input {e:?}"))?;
-
-    let ranges = ranges
-        .into_par_iter()
-        .map(|r| r.filter(|&v| is_invalid_id(v)).sum::<u64>())
-        .sum::<u64>();
-
-    fn is_invalid_id(n: u64) -> bool {
-        let length = n.ilog10() + 1;
-        (1..=(length / 2))
-            .any(|size| length.is_multiple_of(size) && chunk_number(n, size).all_equal())
+        sum_invalid_ids(ranges, is_invalid_id)
     }
+}
+
+fn sum_invalid_ids(
+    ranges: &[RangeInclusive<u64>],
+    is_invalid_id: fn(u64) -> bool,
+) -> Result<Answer, AocError> {
+    ranges
+        .par_iter()
+        .map(|r| {
+            r.clone()
+                .filter(|&v| is_invalid_id(v))
+                .try_fold(0u64, u64::checked_add)
+        })
+        .try_reduce(|| 0, u64::checked_add)
+        .map(Answer::from)
+        .ok_or_else(|| AocError::Unsolvable("The sum of invalid IDs does not fit in u64".into()))
+}
 
-    Ok(ranges.to_string())
+// Zero has one digit, like every other number below ten.
+fn digits(n: u64) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
 }
 
 fn chunk_number(n: u64, by: u32) -> impl Iterator<Item = u64> {
     let divisor = 10u64.pow(by);
-    let total = n.ilog10() / by + 1;
+    let total = (digits(n) - 1) / by + 1;
 
     (0..total).scan(n, move |current, _| {
         let r = *current % divisor;
@@ -63,37 +82,16 @@ fn chunk_number(n: u64, by: u32) -> impl Iterator<Item = u64> {
     })
 }
 
-fn ranges<'src>() -> impl Parser<'src, &'src str, Vec<RangeInclusive<u64>>> {
-    let uint64 = text::int(10).map(|v: &str| v.parse::<u64>().unwrap());
-
-    let pair = uint64
-        .then_ignore(just('-'))
-        .then(uint64)
-        .map(|(start, end)| start..=end);
-
-    pair.separated_by(just(',')).collect()
+fn ranges<'src>() -> impl Parser<'src, &'src str, Vec<RangeInclusive<u64>>, Extra<'src>> {
+    range_inclusive(unsigned())
+        .separated_by(just(','))
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn part1_test() -> anyhow::Result<()> {
-        let input = include_str!("../../samples/02.txt");
-
-        assert_eq!(part1(input)?, "1227775554".to_string());
-        Ok(())
-    }
-
-    #[test]
-    fn part2_test() -> anyhow::Result<()> {
-        let input = include_str!("../../samples/02.txt");
-
-        assert_eq!(part2(input)?, "4174379265".to_string());
-        Ok(())
-    }
-
     #[test]
     fn chunk_number_test() {
         assert_eq!(chunk_number(222, 1).collect::<Vec<_>>(), vec![2, 2, 2]);
@@ -101,4 +99,16 @@ mod tests {
         assert_eq!(chunk_number(998, 2).collect::<Vec<_>>(), vec![98, 9]);
         assert_eq!(chunk_number(11, 1).collect::<Vec<_>>(), vec![1, 1]);
     }
+
+    #[test]
+    fn overflowing_sums_are_unsolvable() {
+        // Nineteen digits can't be split in half, so only part 2 counts these.
+        let input = "9999999999999999999-9999999999999999999,9999999999999999999-9999999999999999999";
+        let error = Day02::part2(&Day02::parse(input).unwrap(), &NoParams {}).unwrap_err();
+        assert!(matches!(error, AocError::Unsolvable(_)), "{error}");
+
+        let input = vec!["999999999999999999-999999999999999999"; 19].join(",");
+        let error = Day02::part1(&Day02::parse(&input).unwrap(), &NoParams {}).unwrap_err();
+        assert!(matches!(error, AocError::Unsolvable(_)), "{error}");
+    }
 }
@@ -1,34 +1,85 @@
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let total = input
-        .lines()
-        .map(|s| s.as_bytes())
-        .map(|bank| solve(bank, 2))
-        .sum::<u64>();
-
-    Ok(total.to_string())
+use crate::{AocError, Answer, Solver, aoc};
+use crate::utils::diagnostics::error_at;
+use serde::Deserialize;
+
+pub struct Day03;
+
+#[aoc(day = 3)]
+impl Solver for Day03 {
+    type Input = Vec<Vec<u8>>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input
+            .lines()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(i, c)| {
+                        c.to_digit(10).map(|d| d as u8).ok_or_else(|| {
+                            let found = &line[i..i + c.len_utf8()];
+                            error_at(3, input, found, format!("found {c:?}, expected a joltage digit"))
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(banks: &Self::Input, params: &Params) -> Result<Answer, AocError> {
+        let total = banks
+            .iter()
+            .map(|bank| solve(bank, params.part1_digits))
+            .sum::<Result<u64, _>>()?;
+
+        Ok(total.into())
+    }
+
+    fn part2(banks: &Self::Input, params: &Params) -> Result<Answer, AocError> {
+        let total = banks
+            .iter()
+            .map(|bank| solve(bank, params.part2_digits))
+            .sum::<Result<u64, _>>()?;
+
+        Ok(total.into())
+    }
 }
 
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let total = input
-        .lines()
-        .map(|s| s.as_bytes())
-        .map(|bank| solve(bank, 12))
-        .sum::<u64>();
+// How many batteries to turn on in each bank.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    part1_digits: usize,
+    part2_digits: usize,
+}
 
-    Ok(total.to_string())
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_digits: 2,
+            part2_digits: 12,
+        }
+    }
 }
 
-fn solve(bank: &[u8], n: usize) -> u64 {
-    let (value, _) = (0..n).fold((0, 0), |(v, start), i| {
+fn solve(bank: &[u8], n: usize) -> Result<u64, AocError> {
+    if bank.len() < n {
+        return Err(AocError::Unsolvable(format!(
+            "A bank of {} batteries can't turn on {n}",
+            bank.len()
+        )));
+    }
+
+    let (value, _) = (0..n).try_fold((0u64, 0), |(v, start), i| {
         let end = bank.len() - (n - 1) + i;
 
-        let (idx, first) = max_index(&bank[start..end]).unwrap();
-        let n = (*first - b'0') as u64;
+        let (idx, &first) = max_index(&bank[start..end])?;
+        let v = v.checked_mul(10)?.checked_add(first as u64)?;
 
-        (v * 10 + n, start + idx + 1)
-    });
+        Some((v, start + idx + 1))
+    })
+    .ok_or_else(|| AocError::Unsolvable(format!("{n} digits do not fit in u64")))?;
 
-    value
+    Ok(value)
 }
 
 fn max_index<T: Ord>(xs: &[T]) -> Option<(usize, &T)> {
@@ -36,22 +87,3 @@ fn max_index<T: Ord>(xs: &[T]) -> Option<(usize, &T)> {
         .enumerate()
         .reduce(|acc, next| if next.1 > acc.1 { next } else { acc })
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &'static str = include_str!("../../samples/03.txt");
-
-    #[test]
-    fn part1_test() -> anyhow::Result<()> {
-        assert_eq!(part1(INPUT)?, "357".to_string());
-        Ok(())
-    }
-
-    #[test]
-    fn part2_test() -> anyhow::Result<()> {
-        assert_eq!(part2(INPUT)?, "3121910778619".to_string());
-        Ok(())
-    }
-}
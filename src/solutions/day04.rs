@@ -1,93 +1,96 @@
+use crate::{AocError, Answer, Solver, aoc};
 use crate::utils::grid::Grid;
+use serde::Deserialize;
 use std::collections::HashSet;
 
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let grid = Grid::parse(input, |c| match c {
-        '@' => Cell::Paper,
-        _ => Cell::Empty,
-    });
+pub struct Day04;
 
-    let total = grid
-        .indices()
-        .filter(|&coord| match grid[coord] {
-            Cell::Paper => {
-                let count = grid
-                    .neighbors(coord)
-                    .filter(|cell: &Option<&Cell>| matches!(cell, Some(Cell::Paper)))
-                    .count();
+#[aoc(day = 4)]
+impl Solver for Day04 {
+    type Input = Grid<Cell>;
+    type Params = Params;
 
-                count < 4
-            }
-            Cell::Empty => false,
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse(4, input, |c| match c {
+            '@' => Cell::Paper,
+            _ => Cell::Empty,
         })
-        .count();
+    }
 
-    Ok(total.to_string())
-}
+    fn part1(grid: &Self::Input, params: &Params) -> Result<Answer, AocError> {
+        let total = grid
+            .indices()
+            .filter(|&coord| match grid[coord] {
+                Cell::Paper => {
+                    let count = grid
+                        .neighbors(coord)
+                        .filter(|cell: &Option<&Cell>| matches!(cell, Some(Cell::Paper)))
+                        .count();
+
+                    count < params.threshold
+                }
+                Cell::Empty => false,
+            })
+            .count();
+
+        Ok(total.into())
+    }
 
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let mut grid = Grid::parse(input, |c| match c {
-        '@' => Cell::Paper,
-        _ => Cell::Empty,
-    });
-
-    let mut to_remove: Vec<_> = grid
-        .indices()
-        .filter(|&pos| {
-            let count = grid
-                .neighbors(pos)
-                .filter(|&cell| matches!(cell, Some(Cell::Paper)))
-                .count();
-
-            matches!(grid[pos], Cell::Paper) && count < 4
-        })
-        .collect();
+    fn part2(grid: &Self::Input, params: &Params) -> Result<Answer, AocError> {
+        let mut grid = grid.clone();
 
-    let mut removed = HashSet::new();
-    while let Some(pos) = to_remove.pop() {
-        if !removed.insert(pos) {
-            continue;
-        }
+        let mut to_remove: Vec<_> = grid
+            .indices()
+            .filter(|&pos| {
+                let count = grid
+                    .neighbors(pos)
+                    .filter(|&cell| matches!(cell, Some(Cell::Paper)))
+                    .count();
 
-        grid[pos] = Cell::Empty;
+                matches!(grid[pos], Cell::Paper) && count < params.threshold
+            })
+            .collect();
 
-        for nbr in grid.neighbor_indices(pos) {
-            let count = grid
-                .neighbors(nbr)
-                .filter(|&cell| matches!(cell, Some(Cell::Paper)))
-                .count();
+        let mut removed = HashSet::new();
+        while let Some(pos) = to_remove.pop() {
+            if !removed.insert(pos) {
+                continue;
+            }
+
+            grid[pos] = Cell::Empty;
 
-            if count < 4 && matches!(grid[nbr], Cell::Paper) {
-                to_remove.push(nbr);
+            for nbr in grid.neighbor_indices(pos) {
+                let count = grid
+                    .neighbors(nbr)
+                    .filter(|&cell| matches!(cell, Some(Cell::Paper)))
+                    .count();
+
+                if count < params.threshold && matches!(grid[nbr], Cell::Paper) {
+                    to_remove.push(nbr);
+                }
             }
         }
-    }
 
-    Ok(removed.len().to_string())
+        Ok(removed.len().into())
+    }
 }
 
-#[derive(Debug)]
-enum Cell {
-    Paper,
-    Empty,
+// A roll can be reached by a forklift when fewer than `threshold` of its
+// neighbours are rolls.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    threshold: usize,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_test() -> anyhow::Result<()> {
-        let input = include_str!("../../samples/04.txt");
-        assert_eq!(part1(input)?, "13".to_string());
-        Ok(())
+impl Default for Params {
+    fn default() -> Self {
+        Params { threshold: 4 }
     }
+}
 
-    #[test]
-    fn part2_test() -> anyhow::Result<()> {
-        let input = include_str!("../../samples/04.txt");
-
-        assert_eq!(part2(input)?, "43".to_string());
-        Ok(())
-    }
+#[derive(Debug, Clone, Copy)]
+pub enum Cell {
+    Paper,
+    Empty,
 }
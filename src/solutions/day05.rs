@@ -1,76 +1,70 @@
-use anyhow::anyhow;
+use crate::{AocError, Answer, NoParams, Solver, aoc};
+use crate::utils::{
+    diagnostics::Extra,
+    parse::{self, blank_line, lines, range_inclusive, unsigned},
+};
 use chumsky::prelude::*;
 use itertools::Itertools;
 use std::ops::RangeInclusive;
 
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let (ranges, ids) = parse_input()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow!("Failed to parse input {e:?}"))?;
+pub struct Day05;
 
-    let answer = ids
-        .iter()
-        .filter(|id| ranges.iter().any(|r| r.contains(id)))
-        .count();
+#[aoc(day = 5)]
+impl Solver for Day05 {
+    type Input = (Vec<RangeInclusive<u64>>, Vec<u64>);
+    type Params = NoParams;
 
-    Ok(answer.to_string())
-}
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::parse_all(5, parse_input(), input)
+    }
 
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let (ranges, _) = parse_input()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow!("Failed to parse input {e:?}"))?;
+    fn part1((ranges, ids): &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let answer = ids
+            .iter()
+            .filter(|id| ranges.iter().any(|r| r.contains(id)))
+            .count();
 
-    let (_, total) = ranges
-        .iter()
-        .sorted_by_key(|r| *r.start())
-        .map(|r| (r.clone(), r.end() - r.start() + 1))
-        .reduce(
-            |(current, total), (range, length)| match current.contains(range.start()) {
-                true if range.end() > current.end() => (
-                    *current.start()..=*range.end(),
-                    total + *range.end() - *current.end(),
-                ),
-                false => (range, total + length),
-                _ => (current, total),
-            },
-        )
-        .unwrap();
+        Ok(answer.into())
+    }
 
-    Ok(total.to_string())
-}
+    fn part2((ranges, _): &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let total = fresh_ids(ranges)
+            .ok_or_else(|| AocError::Unsolvable("More fresh IDs than fit in u64".into()))?;
 
-fn parse_input<'src>() -> impl Parser<'src, &'src str, (Vec<RangeInclusive<u64>>, Vec<u64>)> {
-    let num = text::int(10).map(|v: &str| v.parse::<u64>().unwrap());
-    let ranges = num
-        .then_ignore(just('-'))
-        .then(num)
-        .map(|(start, end)| start..=end)
-        .separated_by(text::newline())
-        .collect();
+        Ok(total.into())
+    }
+}
 
-    let ids = num.separated_by(text::newline()).collect();
+// The number of IDs covered by at least one range, or `None` on overflow.
+fn fresh_ids(ranges: &[RangeInclusive<u64>]) -> Option<u64> {
+    let length = |r: &RangeInclusive<u64>| (r.end() - r.start()).checked_add(1);
 
-    ranges.then_ignore(just("\n\n")).then(ids)
-}
+    let mut sorted = ranges
+        .iter()
+        .filter(|r| !r.is_empty())
+        .sorted_by_key(|r| *r.start());
+    let Some(first) = sorted.next() else {
+        return Some(0);
+    };
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    let (_, total) = sorted.try_fold(
+        (first.clone(), length(first)?),
+        |(current, total), range| match current.contains(range.start()) {
+            true if range.end() > current.end() => Some((
+                *current.start()..=*range.end(),
+                total.checked_add(*range.end() - *current.end())?,
+            )),
+            false => Some((range.clone(), total.checked_add(length(range)?)?)),
+            _ => Some((current, total)),
+        },
+    )?;
 
-    const INPUT: &'static str = include_str!("../../samples/05.txt");
+    Some(total)
+}
 
-    #[test]
-    fn part1_test() -> Result<(), anyhow::Error> {
-        assert_eq!(part1(INPUT)?, "3");
-        Ok(())
-    }
+fn parse_input<'src>() -> impl Parser<'src, &'src str, (Vec<RangeInclusive<u64>>, Vec<u64>), Extra<'src>> {
+    let ranges = lines(range_inclusive(unsigned()));
+    let ids = lines(unsigned());
 
-    #[test]
-    fn part2_test() -> Result<(), anyhow::Error> {
-        assert_eq!(part2(INPUT)?, "14");
-        Ok(())
-    }
+    ranges.then_ignore(blank_line()).then(ids)
 }
@@ -1,56 +1,65 @@
-use anyhow::anyhow;
+use crate::{AocError, Answer, NoParams, Solver, aoc};
+use crate::utils::{
+    diagnostics::Extra,
+    parse::{self, lines, unsigned},
+};
 use chumsky::{
     prelude::*,
     text::{inline_whitespace, newline, whitespace},
 };
 use itertools::Itertools;
 
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let (nums, ops) = parse()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow!("Failed to parse input {e:?}"))?;
-
-    let result: u64 = nums
-        .iter()
-        .zip(ops.iter())
-        .map(|(xs, op)| match op {
-            Op::Add => xs.iter().copied().sum::<u64>(),
-            Op::Mul => xs.iter().copied().product::<u64>(),
-        })
-        .sum();
+pub struct Day06;
 
-    Ok(result.to_string())
+pub struct Worksheet {
+    rows: Vec<Vec<u64>>,
+    columns: Vec<Vec<u64>>,
+    ops: Vec<Op>,
 }
 
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let lines: Vec<_> = input.lines().collect();
-    let (last, rest) = lines.split_last().unwrap();
-    let t = transpose_str(&rest.join("\n"));
-
-    let nums: Vec<Vec<u64>> = t
-        .lines()
-        .map(str::trim)
-        .collect::<Vec<_>>()
-        .split(|line| line.is_empty())
-        .map(|chunk| chunk.iter().flat_map(|s| s.parse::<u64>()).collect())
-        .collect();
+#[aoc(day = 6)]
+impl Solver for Day06 {
+    type Input = Worksheet;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let (rows, ops) = parse::parse_all(6, worksheet(), input)?;
+
+        let lines: Vec<_> = input.lines().collect();
+        let (_, rest) = lines.split_last().unwrap_or((&"", &[]));
+        let t = transpose_str(&rest.join("\n"));
 
-    let ops: Vec<_> = ops()
-        .parse(last)
-        .into_result()
-        .map_err(|e| anyhow!("Failed to parse ops {e:?}"))?;
+        let columns: Vec<Vec<u64>> = t
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .split(|line| line.is_empty())
+            .map(|chunk| chunk.iter().flat_map(|s| s.parse::<u64>()).collect())
+            .collect();
+
+        Ok(Worksheet { rows, columns, ops })
+    }
+
+    fn part1(worksheet: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        Ok(evaluate(&worksheet.rows, &worksheet.ops)?.into())
+    }
+
+    fn part2(worksheet: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        Ok(evaluate(&worksheet.columns, &worksheet.ops)?.into())
+    }
+}
 
-    let result: u64 = nums
-        .iter()
+fn evaluate(nums: &[Vec<u64>], ops: &[Op]) -> Result<u64, AocError> {
+    nums.iter()
         .zip(ops.iter())
-        .map(|(xs, op)| match op {
-            Op::Add => xs.iter().copied().sum::<u64>(),
-            Op::Mul => xs.iter().copied().product::<u64>(),
+        .try_fold(0u64, |total, (xs, op)| {
+            let value = match op {
+                Op::Add => xs.iter().try_fold(0u64, |acc, &x| acc.checked_add(x)),
+                Op::Mul => xs.iter().try_fold(1u64, |acc, &x| acc.checked_mul(x)),
+            };
+            total.checked_add(value?)
         })
-        .sum();
-
-    Ok(result.to_string())
+        .ok_or_else(|| AocError::Unsolvable("The grand total does not fit in u64".into()))
 }
 
 fn transpose<T: Copy>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
@@ -60,39 +69,57 @@ fn transpose<T: Copy>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
         .collect()
 }
 
+// Short lines are padded with spaces, as editors often strip them.
 fn transpose_str(s: &str) -> String {
     let lines: Vec<Vec<_>> = s.lines().map(|l| l.chars().collect()).collect();
-
-    (0..lines[0].len())
-        .map(|i| lines.iter().map(|l| l[i]).collect::<String>())
+    let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..width)
+        .map(|i| {
+            lines
+                .iter()
+                .map(|l| l.get(i).copied().unwrap_or(' '))
+                .collect::<String>()
+        })
         .join("\n")
 }
 
-fn op<'src>() -> impl Parser<'src, &'src str, Op> {
+fn op<'src>() -> impl Parser<'src, &'src str, Op, Extra<'src>> {
     let mul = just('*').to(Op::Mul);
     let add = just('+').to(Op::Add);
     mul.or(add)
 }
 
-fn ops<'src>() -> impl Parser<'src, &'src str, Vec<Op>> {
+fn ops<'src>() -> impl Parser<'src, &'src str, Vec<Op>, Extra<'src>> {
     op().separated_by(whitespace())
         .collect()
         .then_ignore(whitespace())
 }
 
-fn nums<'src>() -> impl Parser<'src, &'src str, Vec<Vec<u64>>> {
-    let num = text::int(10).map(|s: &str| s.parse::<u64>().unwrap());
-
-    let nums = num
+fn nums<'src>() -> impl Parser<'src, &'src str, Vec<Vec<u64>>, Extra<'src>> {
+    let nums = unsigned()
         .padded_by(inline_whitespace())
         .repeated()
         .at_least(1)
         .collect();
 
-    nums.separated_by(newline()).collect().map(transpose)
+    lines(nums).try_map(|rows: Vec<Vec<u64>>, span| {
+        match rows.iter().position(|row| row.len() != rows[0].len()) {
+            Some(i) => Err(Rich::custom(
+                span,
+                format!(
+                    "row {} has {} numbers, expected {}",
+                    i + 1,
+                    rows[i].len(),
+                    rows[0].len()
+                ),
+            )),
+            None => Ok(transpose(rows)),
+        }
+    })
 }
 
-fn parse<'src>() -> impl Parser<'src, &'src str, (Vec<Vec<u64>>, Vec<Op>)> {
+fn worksheet<'src>() -> impl Parser<'src, &'src str, (Vec<Vec<u64>>, Vec<Op>), Extra<'src>> {
     nums().then_ignore(newline()).then(ops())
 }
 
@@ -106,20 +133,6 @@ enum Op {
 mod tests {
     use super::*;
 
-    #[test]
-    fn part1_test() -> anyhow::Result<()> {
-        let input = include_str!("../../samples/06.txt");
-        assert_eq!(part1(input)?, "4277556".to_string());
-        Ok(())
-    }
-
-    #[test]
-    fn part2_test() -> anyhow::Result<()> {
-        let input = include_str!("../../samples/06.txt");
-        assert_eq!(part2(input)?, "3263827".to_string());
-        Ok(())
-    }
-
     #[test]
     fn parse_nums() {
         let test_str = "123 328  51 64 
@@ -148,7 +161,7 @@ mod tests {
     fn parse_test() {
         let input = include_str!("../../samples/06.txt");
 
-        let parsed = parse().parse(input).into_output();
+        let parsed = worksheet().parse(input).into_output();
         assert_eq!(
             parsed,
             Some((
@@ -1,44 +1,87 @@
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let lines: Vec<&str> = input.lines().collect();
-    let (first, rest) = lines.split_first().unwrap();
-
-    let mut cols: Vec<bool> = first.chars().map(|c| c == 'S').collect();
-    let mut count = 0;
-    for row in rest.iter() {
-        for (i, c) in row.char_indices() {
-            if cols[i] && c == '^' {
-                cols[i - 1] = true;
-                cols[i] = false;
-                cols[i + 1] = true;
-                count += 1;
+use crate::{AocError, Answer, NoParams, Solver, aoc};
+
+pub struct Day07;
+
+pub struct Manifold {
+    start: Vec<bool>,
+    rows: Vec<Vec<bool>>,
+}
+
+#[aoc(day = 7)]
+impl Solver for Day07 {
+    type Input = Manifold;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let (first, rest) = lines
+            .split_first()
+            .ok_or_else(|| AocError::InvalidInput("The manifold is empty".into()))?;
+
+        let start = first.chars().map(|c| c == 'S').collect();
+        let rows = rest
+            .iter()
+            .map(|row| row.chars().map(|c| c == '^').collect())
+            .collect();
+
+        Ok(Manifold { start, rows })
+    }
+
+    fn part1(manifold: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let mut cols = manifold.start.clone();
+        let mut count = 0;
+        for (r, row) in manifold.rows.iter().enumerate() {
+            for (i, &splitter) in row.iter().enumerate().take(cols.len()) {
+                if cols[i] && splitter {
+                    check_edge(r, i, cols.len())?;
+                    cols[i - 1] = true;
+                    cols[i] = false;
+                    cols[i + 1] = true;
+                    count += 1;
+                }
             }
         }
+
+        Ok(count.into())
     }
 
-    Ok(count.to_string())
-}
+    fn part2(manifold: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let mut cols: Vec<usize> = manifold
+            .start
+            .iter()
+            .map(|&s| if s { 1 } else { 0 })
+            .collect();
 
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let lines: Vec<&str> = input.lines().collect();
-    let (first, rest) = lines.split_first().unwrap();
-
-    let mut cols: Vec<usize> = first
-        .chars()
-        .map(|c| if c == 'S' { 1 } else { 0 })
-        .collect();
-
-    for row in rest.iter() {
-        for (i, c) in row.char_indices() {
-            if cols[i] > 0 && c == '^' {
-                cols[i - 1] += cols[i];
-                cols[i + 1] += cols[i];
-                cols[i] = 0;
+        for (r, row) in manifold.rows.iter().enumerate() {
+            for (i, &splitter) in row.iter().enumerate().take(cols.len()) {
+                if cols[i] > 0 && splitter {
+                    check_edge(r, i, cols.len())?;
+                    let overflow = || AocError::Unsolvable("Too many timelines to count".into());
+                    cols[i - 1] = cols[i - 1].checked_add(cols[i]).ok_or_else(overflow)?;
+                    cols[i + 1] = cols[i + 1].checked_add(cols[i]).ok_or_else(overflow)?;
+                    cols[i] = 0;
+                }
             }
         }
+
+        let total = cols
+            .iter()
+            .try_fold(0usize, |acc, &n| acc.checked_add(n))
+            .ok_or_else(|| AocError::Unsolvable("Too many timelines to count".into()))?;
+        Ok(total.into())
     }
+}
 
-    let total: usize = cols.iter().sum();
-    Ok(total.to_string())
+// A beam split at either edge would leave the manifold.
+fn check_edge(row: usize, col: usize, width: usize) -> Result<(), AocError> {
+    if col == 0 || col + 1 == width {
+        return Err(AocError::Unsolvable(format!(
+            "A beam is split off the edge of the manifold at row {}, column {}",
+            row + 2,
+            col + 1
+        )));
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -46,18 +89,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn part1_test() -> anyhow::Result<()> {
-        let input = include_str!("../../samples/07.txt");
-
-        assert_eq!(part1(input)?, "21".to_string());
-        Ok(())
-    }
-
-    #[test]
-    fn part2_test() -> anyhow::Result<()> {
-        let input = include_str!("../../samples/07.txt");
+    fn edge_splitters_only_fail_when_hit() -> Result<(), AocError> {
+        let manifold = Day07::parse("..S..\n^...^\n..^..\n")?;
+        assert_eq!(Day07::part1(&manifold, &NoParams {})?, Answer::Unsigned(1));
 
-        assert_eq!(part2(input)?, "40".to_string());
+        let manifold = Day07::parse(".S.\n.^.\n^..\n")?;
+        let error = Day07::part1(&manifold, &NoParams {}).unwrap_err();
+        assert!(matches!(error, AocError::Unsolvable(_)), "{error}");
+        assert!(Day07::part2(&manifold, &NoParams {}).is_err());
         Ok(())
     }
 }
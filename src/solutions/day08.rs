@@ -1,60 +1,88 @@
-use chumsky::{prelude::*, text::newline};
+use crate::{AocError, Answer, Solver, aoc};
+use crate::utils::{
+    diagnostics::Extra,
+    parse::{self, lines, vec3},
+};
+use chumsky::prelude::*;
 use glam::I64Vec3;
 use itertools::Itertools;
+use serde::Deserialize;
 
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    solve1(input, 1000).map(|n| n.to_string())
+pub struct Day08;
+
+#[aoc(day = 8)]
+impl Solver for Day08 {
+    type Input = Vec<I64Vec3>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::parse_all(8, parser(), input)
+    }
+
+    fn part1(boxes: &Self::Input, params: &Params) -> Result<Answer, AocError> {
+        let mut uf = UnionFind::with_size(boxes.len());
+
+        boxes
+            .iter()
+            .enumerate()
+            .tuple_combinations()
+            .sorted_by_key(|&((_, a), (_, b))| distance(a, b))
+            .take(params.connections)
+            .for_each(|((i, _), (j, _))| {
+                uf.union(i, j);
+            });
+
+        let result = uf
+            .sizes
+            .iter()
+            .sorted_by(|a, b| b.cmp(a))
+            .take(3)
+            .product::<usize>();
+
+        Ok(result.into())
+    }
+
+    fn part2(boxes: &Self::Input, _: &Params) -> Result<Answer, AocError> {
+        let mut uf = UnionFind::with_size(boxes.len());
+
+        let result = boxes
+            .iter()
+            .enumerate()
+            .tuple_combinations()
+            .sorted_by_key(|&((_, a), (_, b))| distance(a, b))
+            .find(|&((i, _), (j, _))| {
+                uf.union(i, j);
+                uf.is_connected()
+            })
+            .ok_or_else(|| AocError::Unsolvable("The boxes never form a single circuit".into()))
+            .and_then(|((_, a), (_, b))| {
+                a.x.checked_mul(b.x)
+                    .ok_or_else(|| AocError::Unsolvable(format!("{} * {} overflows", a.x, b.x)))
+            })?;
+
+        Ok(result.into())
+    }
 }
 
-fn solve1(input: &str, n: usize) -> Result<usize, anyhow::Error> {
-    let boxes = parser()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow::anyhow!("Unable to parse input {e:?}"))?;
-
-    let mut uf = UnionFind::with_size(boxes.len());
-
-    boxes
-        .iter()
-        .enumerate()
-        .tuple_combinations()
-        .sorted_by_key(|&((_, a), (_, b))| a.distance_squared(*b))
-        .take(n)
-        .for_each(|((i, _), (j, _))| {
-            uf.union(i, j);
-        });
-
-    let result = uf
-        .sizes
-        .iter()
-        .sorted_by(|a, b| b.cmp(a))
-        .take(3)
-        .product::<usize>();
-
-    Ok(result)
+// Squared, without overflowing for coordinates anywhere in i64.
+fn distance(a: &I64Vec3, b: &I64Vec3) -> u128 {
+    let square = |d: u64| (d as u128) * (d as u128);
+    square(a.x.abs_diff(b.x))
+        .saturating_add(square(a.y.abs_diff(b.y)))
+        .saturating_add(square(a.z.abs_diff(b.z)))
 }
 
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let boxes = parser()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow::anyhow!("Unable to parse input {e:?}"))?;
-
-    let mut uf = UnionFind::with_size(boxes.len());
-
-    let result = boxes
-        .iter()
-        .enumerate()
-        .tuple_combinations()
-        .sorted_by_key(|&((_, a), (_, b))| a.distance_squared(*b))
-        .find(|&((i, _), (j, _))| {
-            uf.union(i, j);
-            uf.is_connected()
-        })
-        .map(|((_, a), (_, b))| a.x * b.x)
-        .unwrap();
-
-    Ok(result.to_string())
+// The puzzle connects the 1000 closest pairs, the sample only 10.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    connections: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { connections: 1000 }
+    }
 }
 
 struct UnionFind {
@@ -108,33 +136,6 @@ impl UnionFind {
     }
 }
 
-fn parser<'src>() -> impl Parser<'src, &'src str, Vec<I64Vec3>> {
-    let num = text::int(10).map(|s: &str| s.parse::<i64>().unwrap());
-    let vec3 = num
-        .then_ignore(just(','))
-        .then(num)
-        .then_ignore(just(','))
-        .then(num)
-        .map(|((x, y), z)| I64Vec3::new(x, y, z));
-
-    vec3.separated_by(newline()).collect()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_test() -> anyhow::Result<()> {
-        let input = include_str!("../../samples/08.txt");
-        assert_eq!(solve1(input, 10)?, 40);
-        Ok(())
-    }
-
-    #[test]
-    fn part2_test() -> anyhow::Result<()> {
-        let input = include_str!("../../samples/08.txt");
-        assert_eq!(part2(input)?, "25272".to_string());
-        Ok(())
-    }
+fn parser<'src>() -> impl Parser<'src, &'src str, Vec<I64Vec3>, Extra<'src>> {
+    lines(vec3())
 }
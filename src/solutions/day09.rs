@@ -1,89 +1,69 @@
-use chumsky::{prelude::*, text::newline};
+use crate::{AocError, Answer, NoParams, Solver, aoc};
+use crate::utils::{
+    diagnostics::Extra,
+    parse::{self, lines, vec2},
+};
+use chumsky::prelude::*;
 use glam::I64Vec2;
 use itertools::Itertools;
 
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let squares = squares()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow::anyhow!("Failed to parse squares: {e:?}"))?;
+pub struct Day09;
 
-    let result = squares
-        .into_iter()
-        .tuple_combinations()
-        .map(|(a, b)| (a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1))
-        .max()
-        .unwrap();
+#[aoc(day = 9)]
+impl Solver for Day09 {
+    type Input = Vec<I64Vec2>;
+    type Params = NoParams;
 
-    Ok(result.to_string())
-}
-
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let squares = squares()
-        .parse(input)
-        .into_result()
-        .map_err(|e| anyhow::anyhow!("Failed to parse squares: {e:?}"))?;
-
-    let lines: Vec<(I64Vec2, I64Vec2)> = squares.iter().copied().circular_tuple_windows().collect();
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::parse_all(9, squares(), input)
+    }
 
-    let result = squares
-        .into_iter()
-        .tuple_combinations()
-        .filter(|(a, b)| {
-            lines.iter().all(|(start, end)| {
-                let left = a.x.max(b.x) <= start.x.min(end.x);
-                let right = a.x.min(b.x) >= start.x.max(end.x);
-                let below = a.y.max(b.y) <= start.y.min(end.y);
-                let above = a.y.min(b.y) >= start.y.max(end.y);
+    fn part1(squares: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let result = largest(squares.iter().tuple_combinations())?;
 
-                left || right || below || above
-            })
-        })
-        .map(|(a, b)| (a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1))
-        .max()
-        .unwrap();
+        Ok(result.into())
+    }
 
-    Ok(result.to_string())
+    fn part2(squares: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let lines: Vec<(I64Vec2, I64Vec2)> =
+            squares.iter().copied().circular_tuple_windows().collect();
+
+        let candidates = squares
+            .iter()
+            .tuple_combinations()
+            .filter(|(a, b)| {
+                lines.iter().all(|(start, end)| {
+                    let left = a.x.max(b.x) <= start.x.min(end.x);
+                    let right = a.x.min(b.x) >= start.x.max(end.x);
+                    let below = a.y.max(b.y) <= start.y.min(end.y);
+                    let above = a.y.min(b.y) >= start.y.max(end.y);
+
+                    left || right || below || above
+                })
+            });
+        let result = largest(candidates)?;
+
+        Ok(result.into())
+    }
 }
 
-fn squares<'src>() -> impl Parser<'src, &'src str, Vec<I64Vec2>> {
-    text::int(10)
-        .then_ignore(just(','))
-        .then(text::int(10))
-        .map(|(a, b): (&str, &str)| {
-            let x = a.parse().unwrap();
-            let y = b.parse().unwrap();
-
-            I64Vec2::new(x, y)
+// The area of the largest rectangle with opposite corners at one of the pairs.
+fn largest<'a>(
+    pairs: impl Iterator<Item = (&'a I64Vec2, &'a I64Vec2)>,
+) -> Result<u64, AocError> {
+    let side = |a: i64, b: i64| a.abs_diff(b).checked_add(1);
+
+    pairs
+        .map(|(a, b)| {
+            side(a.x, b.x)
+                .zip(side(a.y, b.y))
+                .and_then(|(w, h)| w.checked_mul(h))
+                .ok_or_else(|| AocError::Unsolvable(format!("The area of {a} to {b} overflows")))
         })
-        .separated_by(newline())
-        .collect()
+        .process_results(|areas| areas.max())?
+        .ok_or_else(|| AocError::Unsolvable("No rectangle fits between the red tiles".into()))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &'static str = "7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3";
-
-    #[test]
-    fn part1_test() -> anyhow::Result<()> {
-        assert_eq!(part1(INPUT)?, "50".to_string());
-
-        Ok(())
-    }
-
-    #[test]
-    fn part2_test() -> anyhow::Result<()> {
-        assert_eq!(part2(INPUT)?, "24".to_string());
-
-        Ok(())
-    }
+fn squares<'src>() -> impl Parser<'src, &'src str, Vec<I64Vec2>, Extra<'src>> {
+    lines(vec2())
 }
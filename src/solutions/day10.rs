@@ -1,20 +1,73 @@
+use crate::{AocError, Answer, NoParams, Solver, aoc};
+use crate::utils::diagnostics::error_at;
 use good_lp::{Expression, ProblemVariables, Solution, SolverModel, constraint, variable};
 
 use std::collections::{HashSet, VecDeque};
 
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let total: usize = input
-        .lines()
-        .map(|line| {
-            let (pattern, buttons) = parse1(line);
-            solve(pattern, &buttons)
-        })
-        .sum();
+pub struct Day10;
+
+pub struct Machine {
+    pattern: usize,
+    size: usize,
+    buttons: Vec<Vec<usize>>,
+    target: Vec<usize>,
+}
+
+#[aoc(day = 10)]
+impl Solver for Day10 {
+    type Input = Vec<Machine>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input
+            .lines()
+            .map(|line| parse_machine(input, line))
+            .collect()
+    }
+
+    fn part1(machines: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let total: usize = machines
+            .iter()
+            .map(|machine| {
+                let buttons: Vec<usize> = machine
+                    .buttons
+                    .iter()
+                    .map(|button| {
+                        button
+                            .iter()
+                            .fold(0, |n, i| n | 1 << (machine.size - i - 1))
+                    })
+                    .collect();
+
+                solve(machine.pattern, &buttons)
+            })
+            .enumerate()
+            .map(|(i, presses)| {
+                presses.ok_or_else(|| {
+                    AocError::Unsolvable(format!("Machine {} can't match its lights", i + 1))
+                })
+            })
+            .sum::<Result<_, _>>()?;
+
+        Ok(total.into())
+    }
+
+    fn part2(machines: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let mut total: usize = 0;
+        for (i, machine) in machines.iter().enumerate() {
+            let presses = solve2(machine).map_err(|e| {
+                AocError::Unsolvable(format!("Machine {} has no solution: {e}", i + 1))
+            })?;
+            total = total
+                .checked_add(presses)
+                .ok_or_else(|| AocError::Unsolvable("Too many button presses to count".into()))?;
+        }
 
-    Ok(total.to_string())
+        Ok(total.into())
+    }
 }
 
-fn solve(target: usize, options: &[usize]) -> usize {
+fn solve(target: usize, options: &[usize]) -> Option<usize> {
     let mut q = VecDeque::new();
     q.push_back((target, 0));
 
@@ -22,10 +75,10 @@ fn solve(target: usize, options: &[usize]) -> usize {
 
     while let Some((current, n)) = q.pop_front() {
         if current == 0 {
-            return n;
+            return Some(n);
         }
 
-        if !seen.insert((current, n)) {
+        if !seen.insert(current) {
             continue;
         }
 
@@ -34,31 +87,19 @@ fn solve(target: usize, options: &[usize]) -> usize {
         }
     }
 
-    usize::MAX
+    None
 }
 
-fn solve2(line: &str) -> Result<usize, good_lp::ResolutionError> {
-    let parts = line.split_whitespace().skip(1);
-    let (buttons, target): (Vec<_>, Vec<_>) = parts.partition(|p| p.starts_with('('));
-
-    let target: Vec<usize> = target[0]
-        .trim_matches(|c| c == '{' || c == '}')
-        .split(',')
-        .flat_map(|s| s.parse())
-        .collect();
-
+fn solve2(machine: &Machine) -> Result<usize, good_lp::ResolutionError> {
+    let target = &machine.target;
     let n = target.len();
 
-    let buttons: Vec<Vec<usize>> = buttons
+    let buttons: Vec<Vec<usize>> = machine
+        .buttons
         .iter()
-        .map(|b| {
-            let idx = b
-                .trim_matches(|c| c == '(' || c == ')')
-                .split(',')
-                .flat_map(|s| s.parse::<usize>());
-
+        .map(|idx| {
             let mut v = vec![0; n];
-            for i in idx {
+            for &i in idx {
                 v[i] = 1;
             }
             v
@@ -85,63 +126,57 @@ fn solve2(line: &str) -> Result<usize, good_lp::ResolutionError> {
     solution.map(|s| counts.iter().map(|c| s.value(*c).round()).sum::<f64>() as usize)
 }
 
-fn parse1(line: &str) -> (usize, Vec<usize>) {
+fn parse_machine(input: &str, line: &str) -> Result<Machine, AocError> {
+    let invalid = |part: &str, reason: &str| error_at(10, input, part, reason);
+
     let mut parts = line.split_whitespace();
-    let (pattern, size) = parts
+    let token = parts
         .next()
-        .map(|s| {
-            let pattern = s.trim_matches(|c| c == '[' || c == ']');
-            let n = pattern
-                .chars()
-                .fold(0, |acc, next| acc * 2 + if next == '#' { 1 } else { 0 });
-            let size = pattern.len();
-            (n, size)
-        })
-        .unwrap();
+        .ok_or_else(|| invalid(line, "missing light pattern"))?;
+    let pattern = token.trim_matches(|c| c == '[' || c == ']');
+    if pattern.len() > usize::BITS as usize || !pattern.chars().all(|c| c == '.' || c == '#') {
+        return Err(invalid(token, "bad light pattern"));
+    }
+    let size = pattern.len();
+    let pattern = pattern
+        .chars()
+        .fold(0, |acc, next| acc * 2 + if next == '#' { 1 } else { 0 });
 
-    let buttons = parts
-        .take_while(|p| p.starts_with('('))
+    let (buttons, target): (Vec<_>, Vec<_>) = parts.partition(|p| p.starts_with('('));
+
+    let buttons: Vec<Vec<usize>> = buttons
+        .iter()
         .map(|s| {
-            let button = s.trim_matches(|c| c == '(' || c == ')');
-            let mut n: usize = 0;
-            for s in button.split(',') {
-                let i = s.parse::<usize>().unwrap();
-                n |= 1 << (size - i - 1);
+            let button = s
+                .trim_matches(|c| c == '(' || c == ')')
+                .split(',')
+                .map(|s| s.parse::<usize>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| invalid(s, &e.to_string()))?;
+            if button.iter().any(|&i| i >= size) {
+                return Err(invalid(s, "button wired to a missing light"));
             }
-
-            n
+            Ok(button)
         })
-        .collect();
-
-    (pattern, buttons)
-}
-
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let mut total = 0;
-    for result in input.lines().map(solve2) {
-        total += result.expect("Should have been able to solve");
-    }
-
-    Ok(total.to_string())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+        .collect::<Result<_, _>>()?;
 
-    const INPUT: &'static str = include_str!("../../samples/10.txt");
-
-    #[test]
-    fn part1_test() -> anyhow::Result<()> {
-        assert_eq!(part1(INPUT)?, "7".to_string());
-
-        Ok(())
+    let token = target
+        .first()
+        .ok_or_else(|| invalid(line, "missing joltage requirements"))?;
+    let target: Vec<usize> = token
+        .trim_matches(|c| c == '{' || c == '}')
+        .split(',')
+        .map(|s| s.parse::<usize>())
+        .collect::<Result<_, _>>()
+        .map_err(|e| invalid(token, &e.to_string()))?;
+    if target.len() != size {
+        return Err(invalid(token, "one joltage requirement per light expected"));
     }
 
-    #[test]
-    fn part2_test() -> anyhow::Result<()> {
-        assert_eq!(part2(INPUT)?, "33".to_string());
-
-        Ok(())
-    }
+    Ok(Machine {
+        pattern,
+        size,
+        buttons,
+        target,
+    })
 }
@@ -1,56 +1,88 @@
+use crate::{AocError, Answer, NoParams, Solver, aoc};
+use crate::utils::diagnostics::error_at;
 use std::collections::HashMap;
 
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let mut id_map = HashMap::new();
-    let mut graph = Graph::new();
-    for (source, rest) in input.lines().map(|l| l.split_once(": ").unwrap()) {
-        let from = *id_map.entry(source).or_insert_with(|| graph.add_node());
+pub struct Day11;
 
-        for nbr in rest.split_whitespace() {
-            let to = *id_map.entry(nbr).or_insert_with(|| graph.add_node());
-            graph.add_edge(from, to);
+pub struct Network {
+    graph: Graph,
+    ids: HashMap<String, usize>,
+}
+
+#[aoc(day = 11)]
+impl Solver for Day11 {
+    type Input = Network;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut ids = HashMap::new();
+        let mut graph = Graph::new();
+        for line in input.lines() {
+            let (source, rest) = line
+                .split_once(": ")
+                .ok_or_else(|| error_at(11, input, line, "expected \"device: outputs\""))?;
+            let from = *ids
+                .entry(source.to_string())
+                .or_insert_with(|| graph.add_node());
+
+            for nbr in rest.split_whitespace() {
+                let to = *ids
+                    .entry(nbr.to_string())
+                    .or_insert_with(|| graph.add_node());
+                graph.add_edge(from, to);
+            }
         }
+
+        Ok(Network { graph, ids })
     }
 
-    let you = id_map["you"];
-    let out = id_map["out"];
-    let total = graph.count_paths(you, out);
+    fn part1(Network { graph, ids }: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let you = node(ids, "you")?;
+        let out = node(ids, "out")?;
+        let total = graph.count_paths(you, out)?;
 
-    Ok(total.to_string())
-}
+        Ok(total.into())
+    }
 
-pub fn part2(input: &str) -> Result<String, anyhow::Error> {
-    let mut id_map = HashMap::new();
-    let mut graph = Graph::new();
-    for (source, rest) in input.lines().map(|l| l.split_once(": ").unwrap()) {
-        let from = *id_map.entry(source).or_insert_with(|| graph.add_node());
+    fn part2(Network { graph, ids }: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let svr = node(ids, "svr")?;
+        let out = node(ids, "out")?;
+        let dac = node(ids, "dac")?;
+        let fft = node(ids, "fft")?;
 
-        for nbr in rest.split_whitespace() {
-            let to = *id_map.entry(nbr).or_insert_with(|| graph.add_node());
-            graph.add_edge(from, to);
-        }
-    }
+        let svr_to_dac = graph.count_paths(svr, dac)?;
+        let svr_to_fft = graph.count_paths(svr, fft)?;
 
-    let svr = id_map["svr"];
-    let out = id_map["out"];
-    let dac = id_map["dac"];
-    let fft = id_map["fft"];
+        let fft_to_dac = graph.count_paths(fft, dac)?;
+        let dac_to_fft = graph.count_paths(dac, fft)?;
 
-    let svr_to_dac = graph.count_paths(svr, dac);
-    let svr_to_fft = graph.count_paths(svr, fft);
+        let fft_to_out = graph.count_paths(fft, out)?;
+        let dac_to_out = graph.count_paths(dac, out)?;
 
-    let fft_to_dac = graph.count_paths(fft, dac);
-    let dac_to_fft = graph.count_paths(dac, fft);
+        let svr_dac_fft_out = svr_to_dac
+            .checked_mul(dac_to_fft)
+            .and_then(|n| n.checked_mul(fft_to_out));
+        let svr_fft_dac_out = svr_to_fft
+            .checked_mul(fft_to_dac)
+            .and_then(|n| n.checked_mul(dac_to_out));
 
-    let fft_to_out = graph.count_paths(fft, out);
-    let dac_to_out = graph.count_paths(dac, out);
+        let total = svr_dac_fft_out
+            .zip(svr_fft_dac_out)
+            .and_then(|(a, b)| a.checked_add(b))
+            .ok_or_else(too_many_paths)?;
 
-    let svr_dac_fft_out = svr_to_dac * dac_to_fft * fft_to_out;
-    let svr_fft_dac_out = svr_to_fft * fft_to_dac * dac_to_out;
+        Ok(total.into())
+    }
+}
 
-    let total = svr_dac_fft_out + svr_fft_dac_out;
+fn node(ids: &HashMap<String, usize>, name: &str) -> Result<usize, AocError> {
+    ids.get(name)
+        .copied()
+        .ok_or_else(|| AocError::InvalidInput(format!("There is no device named {name}")))
+}
 
-    Ok(total.to_string())
+fn too_many_paths() -> AocError {
+    AocError::Unsolvable("Too many paths to count".into())
 }
 
 struct Graph {
@@ -76,27 +108,53 @@ impl Graph {
         self.adj[node].iter().copied()
     }
 
-    fn count_paths_memo(&self, from: usize, to: usize, memo: &mut Vec<usize>) -> usize {
-        if from == to {
-            return 1;
+    // Depth first with an explicit stack, as a long chain of devices would
+    // overflow the call stack. `memo` holds `None` for devices whose outputs
+    // are still being counted; those are exactly the ones on the path to the
+    // top of the stack, so meeting one again means the devices loop.
+    fn count_paths(&self, from: usize, to: usize) -> Result<usize, AocError> {
+        let mut memo: HashMap<usize, Option<usize>> = HashMap::new();
+        let mut stack = vec![from];
+
+        while let Some(&node) = stack.last() {
+            if node == to {
+                memo.insert(node, Some(1));
+                stack.pop();
+                continue;
+            }
+
+            match memo.get(&node) {
+                None => {
+                    memo.insert(node, None);
+                    for nbr in self.neighbors(node) {
+                        match memo.get(&nbr) {
+                            Some(None) => {
+                                return Err(AocError::Unsolvable(
+                                    "The devices form a loop, so there are endless paths".into(),
+                                ));
+                            }
+                            Some(Some(_)) => {}
+                            None => stack.push(nbr),
+                        }
+                    }
+                }
+                Some(None) => {
+                    let result = self
+                        .neighbors(node)
+                        .filter_map(|nbr| memo.get(&nbr).copied().flatten())
+                        .try_fold(0usize, |acc, n| acc.checked_add(n))
+                        .ok_or_else(too_many_paths)?;
+                    memo.insert(node, Some(result));
+                    stack.pop();
+                }
+                // Pushed by more than one device and already counted.
+                Some(Some(_)) => {
+                    stack.pop();
+                }
+            }
         }
 
-        if memo[from] != usize::MAX {
-            return memo[from];
-        }
-
-        let mut result = 0;
-        for nbr in self.neighbors(from) {
-            result += self.count_paths_memo(nbr, to, memo);
-        }
-        memo[from] = result;
-
-        result
-    }
-
-    fn count_paths(&self, from: usize, to: usize) -> usize {
-        let mut memo = vec![usize::MAX; self.adj.len()];
-        self.count_paths_memo(from, to, &mut memo)
+        Ok(memo.get(&from).copied().flatten().unwrap_or(0))
     }
 }
 
@@ -104,18 +162,28 @@ impl Graph {
 mod tests {
     use super::*;
 
-    const INPUT_1: &'static str = include_str!("../../samples/11.1.txt");
-    const INPUT_2: &'static str = include_str!("../../samples/11.2.txt");
-
     #[test]
-    fn part1_test() -> anyhow::Result<()> {
-        assert_eq!(part1(INPUT_1)?, "5".to_string());
+    fn loops_are_an_error() -> Result<(), AocError> {
+        let network = Day11::parse("you: a\na: b out\nb: a\n")?;
+        let error = Day11::part1(&network, &NoParams {}).unwrap_err();
+        assert!(matches!(error, AocError::Unsolvable(_)), "{error}");
         Ok(())
     }
 
     #[test]
-    fn part2_test() -> anyhow::Result<()> {
-        assert_eq!(part2(INPUT_2)?, "2".to_string());
+    fn long_chains_do_not_overflow_the_stack() -> Result<(), AocError> {
+        let n = 200_000;
+        let mut input = String::from("you: n0\n");
+        for i in 0..n {
+            input.push_str(&format!("n{i}: n{}\n", i + 1));
+        }
+        input.push_str(&format!("n{n}: out\n"));
+
+        let network = Day11::parse(&input)?;
+        assert_eq!(
+            Day11::part1(&network, &NoParams {})?,
+            Answer::Unsigned(1)
+        );
         Ok(())
     }
 }
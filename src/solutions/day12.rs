@@ -1,3 +1,5 @@
+use crate::{AocError, Answer, NoParams, Solver, aoc};
+use crate::utils::diagnostics::error_at;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy)]
@@ -20,58 +22,112 @@ impl Tile {
     }
 }
 
-pub fn part1(input: &str) -> Result<String, anyhow::Error> {
-    let parts: Vec<_> = input.split("\n\n").collect();
-    let (grids, tiles) = parts.split_last().unwrap();
-    let tiles: HashMap<usize, Tile> = tiles.into_iter().map(|s| parse_tile(s)).collect();
-    let grids: Vec<_> = grids.lines().map(|s| parse_grid(s)).collect();
-
-    let total = grids
-        .iter()
-        .filter(|((width, height), counts)| {
-            let total_area = width * height;
-            let needed_area: usize = counts
-                .iter()
-                .map(|(i, count)| tiles[i].area() * count)
-                .sum();
-            needed_area <= total_area
-        })
-        .count();
-
-    Ok(total.to_string())
+pub struct Day12;
+
+// The region's width and length, and how many of each present must fit in it.
+type Region = ((usize, usize), HashMap<usize, usize>);
+
+pub struct Farm {
+    tiles: HashMap<usize, Tile>,
+    regions: Vec<Region>,
+}
+
+#[aoc(day = 12)]
+impl Solver for Day12 {
+    type Input = Farm;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let parts: Vec<_> = input.split("\n\n").collect();
+        let (grids, tiles) = parts
+            .split_last()
+            .ok_or_else(|| AocError::InvalidInput("No regions listed".into()))?;
+        let tiles = tiles
+            .iter()
+            .map(|s| parse_tile(input, s))
+            .collect::<Result<_, _>>()?;
+        let regions = grids
+            .lines()
+            .map(|s| parse_grid(input, s))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Farm { tiles, regions })
+    }
+
+    fn part1(Farm { tiles, regions }: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        let overflow = || AocError::Unsolvable("A region's area does not fit in usize".into());
+
+        let mut total = 0;
+        for ((width, height), counts) in regions {
+            let total_area = width.checked_mul(*height).ok_or_else(overflow)?;
+            let mut needed_area: usize = 0;
+            for (i, count) in counts {
+                let tile = tiles
+                    .get(i)
+                    .ok_or_else(|| AocError::InvalidInput(format!("There is no present {i}")))?;
+                needed_area = tile
+                    .area()
+                    .checked_mul(*count)
+                    .and_then(|area| needed_area.checked_add(area))
+                    .ok_or_else(overflow)?;
+            }
+
+            if needed_area <= total_area {
+                total += 1;
+            }
+        }
+
+        Ok(total.into())
+    }
+
+    fn part2(_input: &Self::Input, _: &Self::Params) -> Result<Answer, AocError> {
+        Ok(Answer::Unsolved)
+    }
 }
 
-fn parse_grid(s: &str) -> ((usize, usize), HashMap<usize, usize>) {
-    let (dim, counts) = s.split_once(": ").unwrap();
+fn parse_grid(input: &str, s: &str) -> Result<Region, AocError> {
+    let invalid = |part: &str, reason: &str| error_at(12, input, part, reason);
+
+    let (dim, counts) = s
+        .split_once(": ")
+        .ok_or_else(|| invalid(s, "expected \"WxL: counts\""))?;
 
-    let (width, length) = dim.split_once('x').unwrap();
-    let width: usize = width.parse().unwrap();
-    let length: usize = length.parse().unwrap();
+    let (width, length) = dim
+        .split_once('x')
+        .ok_or_else(|| invalid(dim, "expected \"WxL\""))?;
+    let width: usize = width.parse().map_err(|_| invalid(width, "invalid width"))?;
+    let length: usize = length.parse().map_err(|_| invalid(length, "invalid length"))?;
 
     let counts: HashMap<usize, usize> = counts
         .split_whitespace()
         .enumerate()
-        .map(|(i, s)| (i, s.parse().unwrap()))
-        .collect();
+        .map(|(i, s)| Ok((i, s.parse().map_err(|_| invalid(s, "invalid count"))?)))
+        .collect::<Result<_, AocError>>()?;
 
-    ((width, length), counts)
+    Ok(((width, length), counts))
 }
 
-fn parse_tile(s: &str) -> (usize, Tile) {
-    let (id, cells) = s.split_once('\n').unwrap();
-    let id: usize = id.trim_end_matches(':').parse().unwrap();
+fn parse_tile(input: &str, s: &str) -> Result<(usize, Tile), AocError> {
+    let invalid = |part: &str, reason: &str| error_at(12, input, part, reason);
+
+    let (id, cells) = s
+        .split_once('\n')
+        .ok_or_else(|| invalid(s, "expected a present's shape below its index"))?;
+    let id: usize = id
+        .trim_end_matches(':')
+        .parse()
+        .map_err(|_| invalid(id, "invalid present index"))?;
 
     let mut fill = [[false; 3]; 3];
 
     for (r, row) in cells.lines().enumerate() {
         for (c, cell) in row.char_indices() {
-            fill[r][c] = cell == '#';
+            *fill
+                .get_mut(r)
+                .and_then(|row| row.get_mut(c))
+                .ok_or_else(|| invalid(&row[c..], "presents are at most 3x3"))? = cell == '#';
         }
     }
 
-    (id, Tile { fill })
-}
-
-pub fn part2(_input: &str) -> Result<String, anyhow::Error> {
-    Ok("Done!".to_string())
+    Ok((id, Tile { fill }))
 }
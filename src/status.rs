@@ -0,0 +1,173 @@
+use crate::{
+    days, input::InputSource, known_answers::KnownAnswers, runner::Status, runner::run_day,
+};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartStatus {
+    NotRegistered,
+    MissingInput,
+    // The solver returned a placeholder rather than an answer.
+    Unsolved,
+    Failed,
+    Answered,
+    Verified,
+    Wrong,
+}
+
+impl PartStatus {
+    fn symbol(self) -> char {
+        match self {
+            PartStatus::NotRegistered => ' ',
+            PartStatus::MissingInput => '.',
+            PartStatus::Unsolved => '-',
+            PartStatus::Failed | PartStatus::Wrong => 'x',
+            PartStatus::Answered => 'o',
+            PartStatus::Verified => '*',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DayStatus {
+    pub day: u32,
+    pub registered: bool,
+    pub input: bool,
+    pub parts: [PartStatus; 2],
+}
+
+// Runs every registered day that has an input and compares its answers with
+// the known ones.
+pub fn collect(
+    year: u32,
+    source: &InputSource,
+    known: &KnownAnswers,
+    params: impl Fn(u32) -> toml::Table,
+) -> Vec<DayStatus> {
+    let registered = days(year);
+
+    (1..=25)
+        .map(|day| {
+            if !registered.contains(&day) {
+                return DayStatus {
+                    day,
+                    registered: false,
+                    input: source.load(year, day).is_ok(),
+                    parts: [PartStatus::NotRegistered; 2],
+                };
+            }
+
+            let run = run_day(year, day, &[1, 2], source, &params(day));
+            let mut parts = [PartStatus::MissingInput; 2];
+            for (status, record) in parts.iter_mut().zip(&run.records) {
+                *status = match (record.status, &record.answer) {
+                    (Status::MissingInput, _) => PartStatus::MissingInput,
+                    (Status::Ok, Some(answer)) if !answer.is_solved() => PartStatus::Unsolved,
+                    (Status::Ok, Some(answer)) => match known.get(day, record.part) {
+                        Some(expected) if expected == answer => PartStatus::Verified,
+                        Some(_) => PartStatus::Wrong,
+                        None => PartStatus::Answered,
+                    },
+                    _ => PartStatus::Failed,
+                };
+            }
+
+            DayStatus {
+                day,
+                registered: true,
+                input: parts.iter().all(|&p| p != PartStatus::MissingInput),
+                parts,
+            }
+        })
+        .collect()
+}
+
+pub fn render(year: u32, days: &[DayStatus]) -> String {
+    let mut out = format!("Advent of Code {year}\n\n");
+
+    for row in days.chunks(5) {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|d| {
+                let [p1, p2] = d.parts.map(PartStatus::symbol);
+                format!("{:>2} [{p1}{p2}]", d.day)
+            })
+            .collect();
+        out.push_str(cells.join("   ").trim_end());
+        out.push('\n');
+    }
+
+    let count = |status| {
+        days.iter()
+            .flat_map(|d| d.parts)
+            .filter(|&p| p == status)
+            .count()
+    };
+    out.push_str(&format!(
+        "\n{} verified, {} answered, {} unsolved, {} failed or wrong, {} missing input\n",
+        count(PartStatus::Verified),
+        count(PartStatus::Answered),
+        count(PartStatus::Unsolved),
+        count(PartStatus::Failed) + count(PartStatus::Wrong),
+        count(PartStatus::MissingInput),
+    ));
+    out.push_str("* verified  o answered  - unsolved  x failed or wrong  . missing input\n");
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AocError, DEFAULT_YEAR};
+    use std::path::PathBuf;
+
+    #[test]
+    fn collect_test() -> Result<(), AocError> {
+        let source = InputSource::Dir(PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/samples"
+        )));
+        let known = KnownAnswers::parse("3 357 1\n4 13 43")?;
+        let status = collect(DEFAULT_YEAR, &source, &known, |_| toml::Table::new());
+
+        assert_eq!(status.len(), 25);
+        assert_eq!(status[2].parts, [PartStatus::Verified, PartStatus::Wrong]);
+        assert_eq!(
+            status[3].parts,
+            [PartStatus::Verified, PartStatus::Verified]
+        );
+        assert_eq!(status[4].parts, [PartStatus::Answered; 2]);
+        assert!(!status[11].input);
+        assert_eq!(status[11].parts, [PartStatus::MissingInput; 2]);
+        assert!(!status[24].registered);
+        Ok(())
+    }
+
+    #[test]
+    fn render_test() {
+        let days: Vec<_> = (1..=25)
+            .map(|day| DayStatus {
+                day,
+                registered: day <= 2,
+                input: day <= 2,
+                parts: match day {
+                    1 => [PartStatus::Verified; 2],
+                    2 => [PartStatus::Answered, PartStatus::Unsolved],
+                    _ => [PartStatus::NotRegistered; 2],
+                },
+            })
+            .collect();
+
+        let grid = render(2025, &days);
+        let lines: Vec<_> = grid.lines().collect();
+        assert_eq!(lines[0], "Advent of Code 2025");
+        assert_eq!(lines[2], " 1 [**]    2 [o-]    3 [  ]    4 [  ]    5 [  ]");
+        assert_eq!(lines[6], "21 [  ]   22 [  ]   23 [  ]   24 [  ]   25 [  ]");
+        assert_eq!(
+            lines[8],
+            "2 verified, 1 answered, 1 unsolved, 0 failed or wrong, 0 missing input"
+        );
+    }
+}
@@ -0,0 +1,333 @@
+use crate::{
+    Answer, AocError,
+    input::InputSource,
+    runner::{DayRun, Record, Status, catch},
+    solution_for,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    io::{Read, Write},
+    path::Path,
+    process::{Command, ExitStatus, Stdio},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+use wait_timeout::ChildExt;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub max_memory: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkerReport {
+    pub parse: Duration,
+    pub duration: Duration,
+    pub outcome: Result<Answer, (Status, String)>,
+}
+
+// Runs inside the worker process: parse the input and solve a single part.
+pub fn work(year: u32, day: u32, part: u32, input: &str, params: &toml::Table) -> WorkerReport {
+    let now = Instant::now();
+    let parsed = catch(|| solution_for(year, day)?(input, params));
+    let parse = now.elapsed();
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err((status, e)) => {
+            return WorkerReport {
+                parse,
+                duration: Duration::ZERO,
+                outcome: Err((status, format!("Failed to parse input: {e}"))),
+            };
+        }
+    };
+
+    let now = Instant::now();
+    let outcome = catch(|| {
+        if part == 1 {
+            parsed.part1()
+        } else {
+            parsed.part2()
+        }
+    });
+
+    WorkerReport {
+        parse,
+        duration: now.elapsed(),
+        outcome,
+    }
+}
+
+// Runs each part of `day` in a separate `<exe> worker <year> <day> <part>`
+// process so that a runaway solver can be killed without taking the runner down.
+// Parameters are handed to the worker as a TOML string.
+pub fn run_day_supervised(
+    exe: &Path,
+    year: u32,
+    day: u32,
+    parts: &[u32],
+    source: &InputSource,
+    params: &toml::Table,
+    limits: Limits,
+) -> DayRun {
+    let failed = |status, message: String| DayRun {
+        year,
+        day,
+        parse: None,
+        records: parts
+            .iter()
+            .map(|&part| Record::failed(day, part, status, message.clone()))
+            .collect(),
+    };
+
+    if let Err(e) = solution_for(year, day) {
+        return failed(Status::NoSolution, e.to_string());
+    }
+
+    let input = match source.load(year, day) {
+        Ok(input) => input,
+        Err(e @ AocError::MissingInput { .. }) => {
+            return failed(Status::MissingInput, e.to_string());
+        }
+        Err(e) => return failed(Status::Error, e.to_string()),
+    };
+
+    let mut parse = None;
+    let records = parts
+        .iter()
+        .map(
+            |&part| match spawn_worker(exe, year, day, part, &input, params, limits) {
+                Ok(report) => {
+                    parse.get_or_insert(report.parse);
+                    match report.outcome {
+                        Ok(answer) => Record {
+                            day,
+                            part,
+                            answer: Some(answer),
+                            duration: report.duration,
+                            status: Status::Ok,
+                            message: None,
+                        },
+                        Err((status, message)) => Record {
+                            duration: report.duration,
+                            ..Record::failed(day, part, status, message)
+                        },
+                    }
+                }
+                Err((status, message)) => Record::failed(day, part, status, message),
+            },
+        )
+        .collect();
+
+    DayRun {
+        year,
+        day,
+        parse,
+        records,
+    }
+}
+
+fn spawn_worker(
+    exe: &Path,
+    year: u32,
+    day: u32,
+    part: u32,
+    input: &str,
+    params: &toml::Table,
+    limits: Limits,
+) -> Result<WorkerReport, (Status, String)> {
+    let error = |e: std::io::Error| (Status::Error, format!("Worker failed: {e}"));
+
+    let mut command = Command::new(exe);
+    command
+        .args([
+            "worker",
+            &year.to_string(),
+            &day.to_string(),
+            &part.to_string(),
+            "--params",
+            &params.to_string(),
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if let Some(bytes) = limits.max_memory {
+        limit_memory(&mut command, bytes).map_err(|message| (Status::Error, message))?;
+    }
+
+    let mut child = command.spawn().map_err(error)?;
+
+    // Drain the pipes on their own threads so a chatty worker can't block on a
+    // full pipe and be mistaken for a timeout.
+    let stdout = child.stdout.take().map(read_in_background);
+    let stderr = child.stderr.take().map(read_in_background);
+
+    // A worker that dies early closes its stdin, which is reported below.
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(input.as_bytes());
+    }
+
+    let status = match limits.timeout {
+        Some(timeout) => match child.wait_timeout(timeout).map_err(error)? {
+            Some(status) => status,
+            None => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err((
+                    Status::TimedOut,
+                    format!("timed out after {}", humantime::format_duration(timeout)),
+                ));
+            }
+        },
+        None => child.wait().map_err(error)?,
+    };
+
+    let join =
+        |handle: Option<JoinHandle<String>>| handle.and_then(|h| h.join().ok()).unwrap_or_default();
+    let stdout = join(stdout);
+    let stderr = join(stderr);
+
+    if let Ok(report) = serde_json::from_str(&stdout) {
+        return Ok(report);
+    }
+
+    // Under a memory limit an allocation can also fail as a segfault or abort
+    // rather than a clean error, so any death by signal counts as going over.
+    let over_limit = limits.max_memory.is_some() && killed_by_signal(&status);
+    if over_limit || stderr.contains("memory allocation of") || stderr.contains("capacity overflow")
+    {
+        let message = match limits.max_memory {
+            Some(bytes) => format!("exceeded memory limit of {}", format_bytes(bytes)),
+            None => "ran out of memory".to_string(),
+        };
+        return Err((Status::OutOfMemory, message));
+    }
+
+    let last_line = stderr.lines().last().unwrap_or_default();
+    let failed = if killed_by_signal(&status) {
+        Status::Crashed
+    } else {
+        Status::Error
+    };
+    Err((failed, format!("Worker exited with {status}: {last_line}")))
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut out = String::new();
+        let _ = pipe.read_to_string(&mut out);
+        out
+    })
+}
+
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, bytes: u64) -> Result<(), String> {
+    use std::os::unix::process::CommandExt;
+
+    // SAFETY: setrlimit is async-signal-safe and only touches the child.
+    unsafe {
+        command.pre_exec(move || {
+            let limit = libc::rlimit {
+                rlim_cur: bytes as libc::rlim_t,
+                rlim_max: bytes as libc::rlim_t,
+            };
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_command: &mut Command, _bytes: u64) -> Result<(), String> {
+    Err("--max-memory is only supported on unix".to_string())
+}
+
+#[cfg(unix)]
+fn killed_by_signal(status: &ExitStatus) -> bool {
+    use std::os::unix::process::ExitStatusExt;
+
+    status.signal().is_some()
+}
+
+#[cfg(not(unix))]
+fn killed_by_signal(_status: &ExitStatus) -> bool {
+    false
+}
+
+pub fn parse_bytes(s: &str) -> Result<u64, AocError> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (digits, unit) = s.split_at(split);
+
+    let n: u64 = digits
+        .parse()
+        .map_err(|_| AocError::InvalidArgument(format!("Invalid size {s:?}")))?;
+    let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1 << 10,
+        "m" | "mb" | "mib" => 1 << 20,
+        "g" | "gb" | "gib" => 1 << 30,
+        _ => {
+            return Err(AocError::InvalidArgument(format!(
+                "Unknown size unit {unit:?} in {s:?}"
+            )));
+        }
+    };
+
+    n.checked_mul(multiplier)
+        .ok_or_else(|| AocError::InvalidArgument(format!("Size {s:?} is too large")))
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1 << 30 && b.is_multiple_of(1 << 30) => format!("{}GiB", b >> 30),
+        b if b >= 1 << 20 && b.is_multiple_of(1 << 20) => format!("{}MiB", b >> 20),
+        b if b >= 1 << 10 && b.is_multiple_of(1 << 10) => format!("{}KiB", b >> 10),
+        b => format!("{b}B"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_YEAR;
+
+    #[test]
+    fn parse_bytes_test() -> Result<(), AocError> {
+        assert_eq!(parse_bytes("1024")?, 1024);
+        assert_eq!(parse_bytes("512M")?, 512 << 20);
+        assert_eq!(parse_bytes("2GiB")?, 2 << 30);
+        assert_eq!(parse_bytes("64 kb")?, 64 << 10);
+        assert!(parse_bytes("lots").is_err());
+        assert!(parse_bytes("12T").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn format_bytes_test() {
+        assert_eq!(format_bytes(512 << 20), "512MiB");
+        assert_eq!(format_bytes(4 << 10), "4KiB");
+        assert_eq!(format_bytes(1536), "1536B");
+    }
+
+    #[test]
+    fn work_test() {
+        let report = work(
+            DEFAULT_YEAR,
+            3,
+            2,
+            include_str!("../samples/03.txt"),
+            &toml::Table::new(),
+        );
+        assert_eq!(report.outcome, Ok(Answer::Unsigned(3121910778619)));
+
+        let report = work(DEFAULT_YEAR, 30, 1, "", &toml::Table::new());
+        assert!(matches!(report.outcome, Err((Status::Error, _))));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::AocError;
+use chumsky::{
+    error::{Rich, RichPattern, RichReason},
+    prelude::*,
+};
+use std::ops::Range;
+
+// The parser extra every chumsky-based day uses, so failures carry enough
+// detail to be rendered.
+pub type Extra<'src> = extra::Err<Rich<'src, char>>;
+
+// Locates `error` in the input and renders the offending line with a caret
+// under the span, e.g.
+//
+//   Day 8, line 3, column 5: found 'x', expected '-' or digit
+//     |
+//   3 | 12,x
+//     |    ^
+pub fn to_error(day: u32, input: &str, error: &Rich<'_, char>) -> AocError {
+    let span = error.span();
+    render(day, input, span.start..span.end, message(error.reason()))
+}
+
+// The same for parsers that split the input by hand: `part` is the offending
+// slice of `input`, such as a line or a token within it.
+pub fn error_at(day: u32, input: &str, part: &str, message: impl Into<String>) -> AocError {
+    let start = (part.as_ptr() as usize).checked_sub(input.as_ptr() as usize);
+    debug_assert!(
+        start.is_some_and(|start| start + part.len() <= input.len()),
+        "{part:?} is not part of the input"
+    );
+    let start = start.unwrap_or(0).min(input.len());
+
+    render(day, input, start..start + part.len(), message.into())
+}
+
+fn render(day: u32, input: &str, span: Range<usize>, message: String) -> AocError {
+    let start = span.start.min(input.len());
+    let end = span.end.clamp(start, input.len());
+
+    let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+    let source = input[line_start..line_end].trim_end_matches('\r');
+
+    let line = input[..start].matches('\n').count() + 1;
+    let column = input[line_start..start].chars().count() + 1;
+    let width = input[start..end.min(line_end)].chars().count().max(1);
+
+    let gutter = " ".repeat(line.to_string().len());
+    let snippet = format!(
+        "{gutter} |\n{line} | {source}\n{gutter} | {}{}",
+        " ".repeat(column - 1),
+        "^".repeat(width)
+    );
+
+    AocError::Parse {
+        day,
+        line,
+        column,
+        message,
+        snippet,
+    }
+}
+
+fn message(reason: &RichReason<'_, char>) -> String {
+    let RichReason::ExpectedFound { expected, found } = reason else {
+        return reason.to_string();
+    };
+
+    let found = match found {
+        Some(c) => format!("{:?}", **c),
+        None => "end of input".to_string(),
+    };
+
+    let mut expected: Vec<_> = expected
+        .iter()
+        .map(|pattern| match pattern {
+            // Debug escapes newlines and the like.
+            RichPattern::Token(c) => format!("{:?}", **c),
+            pattern => pattern.to_string(),
+        })
+        .collect();
+    expected.sort();
+    expected.dedup();
+
+    match expected.split_last() {
+        None => format!("found {found}"),
+        Some((last, [])) => format!("found {found}, expected {last}"),
+        Some((last, rest)) => format!("found {found}, expected {} or {last}", rest.join(", ")),
+    }
+}
+
+// Runs `parser` over the whole input, reporting the first error as an
+// `AocError::Parse` for `day`.
+pub fn parse_input<'src, T>(
+    day: u32,
+    parser: impl Parser<'src, &'src str, T, Extra<'src>>,
+    input: &'src str,
+) -> Result<T, AocError> {
+    parser
+        .parse(input)
+        .into_result()
+        .map_err(|errors| match errors.first() {
+            Some(first) => to_error(day, input, first),
+            None => AocError::InvalidInput(format!("Day {day}: unable to parse input")),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs<'src>() -> impl Parser<'src, &'src str, Vec<(u32, u32)>, Extra<'src>> {
+        let num = text::int(10).from_str::<u32>().unwrapped();
+        num.then_ignore(just('-'))
+            .then(num)
+            .separated_by(text::newline())
+            .collect()
+    }
+
+    #[test]
+    fn renders_location_and_expected_tokens() {
+        let error = parse_input(5, pairs(), "1-2\n3-4\n56+7").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Day 5, line 3, column 3: found '+', expected '-' or digit\n  \
+             |\n\
+             3 | 56+7\n  \
+             |   ^"
+        );
+
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                day: 5,
+                line: 3,
+                column: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn reports_end_of_input() {
+        let error = parse_input(2, pairs(), "1-2\n3-").unwrap_err();
+        let first = error.to_string();
+        let first = first.lines().next().unwrap();
+
+        assert!(
+            first.starts_with("Day 2, line 2, column 3: found end of input"),
+            "{first}"
+        );
+    }
+
+    #[test]
+    fn points_at_a_slice_of_the_input() {
+        let input = "ab: c\nde fg";
+        let line = input.lines().nth(1).unwrap();
+        let error = error_at(11, input, &line[3..], "expected \"device: outputs\"");
+
+        assert_eq!(
+            error.to_string(),
+            "Day 11, line 2, column 4: expected \"device: outputs\"\n  \
+             |\n\
+             2 | de fg\n  \
+             |    ^^"
+        );
+    }
+}
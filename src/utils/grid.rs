@@ -1,3 +1,5 @@
+use crate::AocError;
+use crate::utils::diagnostics::error_at;
 use std::ops::{Index, IndexMut};
 
 use glam::IVec2;
@@ -13,6 +15,7 @@ const ALL_DIRS: [IVec2; 8] = [
     IVec2::new(1, -1),
 ];
 
+#[derive(Clone)]
 pub struct Grid<T> {
     data: Vec<T>,
     pub width: usize,
@@ -36,17 +39,28 @@ impl<T> IndexMut<IVec2> for Grid<T> {
 }
 
 impl<T> Grid<T> {
-    pub fn parse(input: &str, f: fn(char) -> T) -> Self {
+    // Every row must be as wide as the first.
+    pub fn parse(day: u32, input: &str, f: fn(char) -> T) -> Result<Self, AocError> {
         let lines: Vec<_> = input.lines().collect();
         let height = lines.len();
-        let width = lines[0].len();
+        let width = lines.first().map_or(0, |l| l.chars().count());
+
+        if let Some(line) = lines.iter().find(|l| l.chars().count() != width) {
+            return Err(error_at(
+                day,
+                input,
+                line,
+                format!("found {} cells, expected {width}", line.chars().count()),
+            ));
+        }
+
         let data: Vec<_> = lines.iter().flat_map(|s| s.chars()).map(f).collect();
 
-        Grid {
+        Ok(Grid {
             data,
             width,
             height,
-        }
+        })
     }
 
     fn index(&self, i: IVec2) -> Option<usize> {
@@ -56,7 +70,7 @@ impl<T> Grid<T> {
         if col < 0 || col >= self.width as i32 || row < 0 || row >= self.height as i32 {
             None
         } else {
-            Some(row as usize * self.height + col as usize)
+            Some(row as usize * self.width + col as usize)
         }
     }
 
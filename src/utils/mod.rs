@@ -1 +1,3 @@
+pub mod diagnostics;
 pub mod grid;
+pub mod parse;
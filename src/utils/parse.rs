@@ -0,0 +1,141 @@
+use crate::{
+    AocError,
+    utils::diagnostics::{self, Extra},
+};
+use chumsky::{error::Rich, prelude::*};
+use glam::{I64Vec2, I64Vec3};
+use std::{ops::RangeInclusive, str::FromStr};
+
+// Overflow is reported as a parse error at the number rather than a panic.
+fn checked<'src, T: FromStr>(s: &'src str, span: SimpleSpan) -> Result<T, Rich<'src, char>> {
+    s.parse().map_err(|_| {
+        let name = std::any::type_name::<T>();
+        Rich::custom(span, format!("{s} does not fit in {name}"))
+    })
+}
+
+pub fn unsigned<'src, T: FromStr>() -> impl Parser<'src, &'src str, T, Extra<'src>> + Clone {
+    text::int(10).try_map(checked)
+}
+
+pub fn signed<'src, T: FromStr>() -> impl Parser<'src, &'src str, T, Extra<'src>> + Clone {
+    just('-')
+        .or_not()
+        .then(text::int(10))
+        .to_slice()
+        .try_map(checked)
+}
+
+// `a-b`, e.g. `11-22`.
+pub fn range_inclusive<'src, T>(
+    bound: impl Parser<'src, &'src str, T, Extra<'src>> + Clone,
+) -> impl Parser<'src, &'src str, RangeInclusive<T>, Extra<'src>> + Clone {
+    bound
+        .clone()
+        .then_ignore(just('-'))
+        .then(bound)
+        .map(|(start, end)| start..=end)
+}
+
+// `x,y`
+pub fn vec2<'src>() -> impl Parser<'src, &'src str, I64Vec2, Extra<'src>> + Clone {
+    signed()
+        .then_ignore(just(','))
+        .then(signed())
+        .map(|(x, y)| I64Vec2::new(x, y))
+}
+
+// `x,y,z`
+pub fn vec3<'src>() -> impl Parser<'src, &'src str, I64Vec3, Extra<'src>> + Clone {
+    signed()
+        .then_ignore(just(','))
+        .then(signed())
+        .then_ignore(just(','))
+        .then(signed())
+        .map(|((x, y), z)| I64Vec3::new(x, y, z))
+}
+
+// One `item` per line. A trailing newline is left for `parse_all`, so `lines`
+// can be followed by a `blank_line`.
+pub fn lines<'src, T>(
+    item: impl Parser<'src, &'src str, T, Extra<'src>> + Clone,
+) -> impl Parser<'src, &'src str, Vec<T>, Extra<'src>> + Clone {
+    item.separated_by(text::newline()).at_least(1).collect()
+}
+
+pub fn blank_line<'src>() -> impl Parser<'src, &'src str, (), Extra<'src>> + Clone {
+    text::newline().then(text::newline()).ignored()
+}
+
+// Blocks separated by blank lines.
+pub fn sections<'src, T>(
+    section: impl Parser<'src, &'src str, T, Extra<'src>> + Clone,
+) -> impl Parser<'src, &'src str, Vec<T>, Extra<'src>> + Clone {
+    section.separated_by(blank_line()).at_least(1).collect()
+}
+
+// Runs `parser` over the whole of `input`, allowing only trailing newlines
+// after it, and renders any failure as a diagnostic for `day`.
+pub fn parse_all<'src, T>(
+    day: u32,
+    parser: impl Parser<'src, &'src str, T, Extra<'src>>,
+    input: &'src str,
+) -> Result<T, AocError> {
+    let parser = parser
+        .then_ignore(text::newline().repeated())
+        .then_ignore(end());
+    diagnostics::parse_input(day, parser, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse<'src, T>(
+        parser: impl Parser<'src, &'src str, T, Extra<'src>>,
+        input: &'src str,
+    ) -> Result<T, String> {
+        parse_all(0, parser, input).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn integers_test() {
+        assert_eq!(parse(unsigned::<u8>(), "255"), Ok(255));
+        assert_eq!(parse(signed::<i32>(), "-17"), Ok(-17));
+        assert_eq!(parse(signed::<i32>(), "17"), Ok(17));
+
+        let error = parse(unsigned::<u8>(), "256").unwrap_err();
+        assert!(error.contains("256 does not fit in u8"), "{error}");
+        let error = parse(signed::<i64>(), "-9223372036854775809").unwrap_err();
+        assert!(error.contains("does not fit in i64"), "{error}");
+        assert!(parse(unsigned::<u32>(), "-1").is_err());
+    }
+
+    #[test]
+    fn combinators_test() {
+        assert_eq!(
+            parse(range_inclusive(unsigned::<u64>()), "11-22"),
+            Ok(11..=22)
+        );
+        assert_eq!(parse(vec2(), "7,-1"), Ok(I64Vec2::new(7, -1)));
+        assert_eq!(
+            parse(vec3(), "162,817,812"),
+            Ok(I64Vec3::new(162, 817, 812))
+        );
+        assert_eq!(
+            parse(lines(unsigned::<u32>()), "1\n2\n3\n"),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            parse(sections(lines(unsigned::<u32>())), "1\n2\n\n3\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn parse_all_requires_end_of_input() {
+        let error = parse(lines(unsigned::<u32>()), "1\n2\nthree").unwrap_err();
+        assert!(error.starts_with("Day 0, line 3, column 1"), "{error}");
+        assert!(parse(lines(unsigned::<u32>()), "1\n2\n\n").is_ok());
+    }
+}
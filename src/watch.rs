@@ -0,0 +1,202 @@
+use crate::{AocError, config::Config, input, runner::Status, samples, scaffold};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    process::{Command, Stdio},
+    time::SystemTime,
+};
+
+pub type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+// The solution module, real input, sample manifest and every `samples/NN*.txt`
+// for `day`. The samples directory is listed afresh each time so new sample
+// files are noticed.
+pub fn watched_paths(config: &Config, year: u32, day: u32) -> Vec<PathBuf> {
+    let mut paths = vec![config.root.join(scaffold::module_path(year, day))];
+    paths.extend(input::candidates(&config.inputs_dir, year, day));
+    paths.push(config.samples_for(year).join(samples::MANIFEST));
+
+    let prefix = format!("{day:02}");
+    if let Ok(entries) = std::fs::read_dir(config.samples_for(year)) {
+        let mut samples: Vec<_> = entries
+            .flat_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+            })
+            .collect();
+        samples.sort();
+        paths.extend(samples);
+    }
+
+    paths
+}
+
+pub fn snapshot(config: &Config, year: u32, day: u32) -> Snapshot {
+    watched_paths(config, year, day)
+        .into_iter()
+        .filter_map(|path| {
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct RunRecord {
+    part: u32,
+    answer: serde_json::Value,
+    status: Status,
+    message: Option<String>,
+}
+
+// Summarises the json output of `day <day>` as one line of text per part.
+pub fn describe(json: &str) -> Result<BTreeMap<u32, String>, AocError> {
+    let records: Vec<RunRecord> = serde_json::from_str(json)?;
+
+    Ok(records
+        .into_iter()
+        .map(|r| {
+            let summary = match (r.status, r.answer) {
+                (Status::Ok, serde_json::Value::Null) => "unsolved".to_string(),
+                (Status::Ok, serde_json::Value::String(s)) => s,
+                (Status::Ok, answer) => answer.to_string(),
+                (status, _) => {
+                    let message = r.message.as_deref().and_then(|m| m.lines().next());
+                    format!("failed: {}", message.unwrap_or(status.as_str()))
+                }
+            };
+            (r.part, summary)
+        })
+        .collect())
+}
+
+pub fn diff(previous: &BTreeMap<u32, String>, current: &BTreeMap<u32, String>) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, now)| match previous.get(part) {
+            None => format!("Part {part}: {now}"),
+            Some(before) if before == now => format!("Part {part}: {now} (unchanged)"),
+            Some(before) => format!("Part {part}: {now} (was {before})"),
+        })
+        .collect()
+}
+
+// How far a rebuild got.
+#[derive(Debug)]
+pub enum Rebuild {
+    BuildFailed,
+    SamplesFailed,
+    Solved(BTreeMap<u32, String>),
+}
+
+// Rebuilds, runs the day's sample tests and then the real input, stopping at
+// the first step that fails. Cargo's own output is passed through so compile
+// errors and failing assertions show up.
+pub fn rebuild(config: &Config, year: u32, day: u32, release: bool) -> Result<Rebuild, AocError> {
+    let cargo = |args: &[&str]| {
+        let mut command = Command::new("cargo");
+        command.current_dir(&config.root).args(args);
+        if release {
+            command.arg("--release");
+        }
+        command
+    };
+
+    let spawn_error = |e| AocError::io("Unable to run cargo", e);
+
+    if !cargo(&["build", "-q"])
+        .status()
+        .map_err(spawn_error)?
+        .success()
+    {
+        return Ok(Rebuild::BuildFailed);
+    }
+
+    // The module's own tests and those generated from the sample manifest.
+    let module = scaffold::module_name(year, day);
+    if !cargo(&["test", "-q", "--lib"])
+        .args(["--", &format!("{module}::"), &format!("{module}_samples::")])
+        .status()
+        .map_err(spawn_error)?
+        .success()
+    {
+        return Ok(Rebuild::SamplesFailed);
+    }
+
+    // A failing part makes `day` exit with an error, but still prints its json.
+    let output = cargo(&["run", "-q"])
+        .args(["--", "--year", &year.to_string(), "day", &day.to_string()])
+        .args(["--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(spawn_error)?;
+
+    describe(&String::from_utf8_lossy(&output.stdout)).map(Rebuild::Solved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_YEAR;
+
+    #[test]
+    fn notices_new_samples() -> Result<(), AocError> {
+        let root = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("samples"))?;
+        let config = Config {
+            inputs_dir: root.join("inputs"),
+            samples_dir: root.join("samples"),
+            root: root.clone(),
+            ..Config::default()
+        };
+
+        std::fs::write(root.join("samples/11.1.txt"), "")?;
+        std::fs::write(root.join("samples/01.txt"), "")?;
+        let before = snapshot(&config, DEFAULT_YEAR, 11);
+        assert_eq!(
+            before.keys().collect::<Vec<_>>(),
+            [&root.join("samples/11.1.txt")]
+        );
+
+        std::fs::write(root.join("samples/11.2.txt"), "")?;
+        let after = snapshot(&config, DEFAULT_YEAR, 11);
+        assert_ne!(after, before);
+
+        std::fs::write(root.join("samples/samples.toml"), "")?;
+        assert_ne!(snapshot(&config, DEFAULT_YEAR, 11), after);
+
+        std::fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+
+    #[test]
+    fn diff_test() -> Result<(), AocError> {
+        let previous = describe(
+            r#"[{"day":3,"part":1,"answer":357,"duration_ns":1,"status":"ok"},
+                {"day":3,"part":2,"answer":null,"duration_ns":1,"status":"ok"}]"#,
+        )?;
+        let current = describe(
+            r#"[{"day":3,"part":1,"answer":357,"duration_ns":1,"status":"ok"},
+                {"day":3,"part":2,"answer":null,"duration_ns":1,"status":"panicked",
+                 "message":"panicked: index out of bounds"}]"#,
+        )?;
+
+        assert_eq!(
+            diff(&BTreeMap::new(), &previous),
+            ["Part 1: 357", "Part 2: unsolved"]
+        );
+        assert_eq!(
+            diff(&previous, &current),
+            [
+                "Part 1: 357 (unchanged)",
+                "Part 2: failed: panicked: index out of bounds (was unsolved)"
+            ]
+        );
+        Ok(())
+    }
+}
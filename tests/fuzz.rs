@@ -0,0 +1,182 @@
+// Feeds random and mutated sample inputs to every registered day, each part in
+// its own worker process, and fails if any of them panics, crashes or runs out
+// of memory. Solvers are expected to report bad input as an error instead.
+//
+// `AOC_FUZZ_CASES` sets the number of inputs per day and `AOC_FUZZ_SEED` the
+// starting seed, which is printed with every failure so it can be replayed.
+
+use aoc_2025::{
+    DEFAULT_YEAR, days,
+    input::InputSource,
+    runner::Status,
+    samples::Manifest,
+    supervise::{Limits, run_day_supervised},
+};
+use std::{path::Path, time::Duration};
+
+const DEFAULT_CASES: u64 = 24;
+const DEFAULT_SEED: u64 = 0x2025_0c0d_e5ee_d5a1;
+
+// Numbers at the edges of the types the solvers parse into.
+const EDGE_NUMBERS: &[&str] = &[
+    "0",
+    "1",
+    "-1",
+    "2147483647",
+    "4294967296",
+    "9223372036854775807",
+    "18446744073709551615",
+    "99999999999999999999999",
+];
+
+// xorshift64*, so the harness needs no extra dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n.max(1) as u64) as usize
+    }
+
+    fn pick<'a, T>(&mut self, xs: &'a [T]) -> &'a T {
+        &xs[self.below(xs.len())]
+    }
+}
+
+fn env_or(name: &str, default: u64) -> u64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(default)
+}
+
+// Characters drawn from the samples themselves are far more likely to get past
+// a parser than arbitrary bytes.
+fn arbitrary(rng: &mut Rng, alphabet: &[char]) -> String {
+    let len = rng.below(200);
+    (0..len).map(|_| *rng.pick(alphabet)).collect()
+}
+
+fn mutate(rng: &mut Rng, sample: &str, alphabet: &[char]) -> String {
+    let mut chars: Vec<char> = sample.chars().collect();
+
+    for _ in 0..=rng.below(4) {
+        let at = rng.below(chars.len() + 1);
+        match rng.below(7) {
+            0 if at < chars.len() => {
+                chars.remove(at);
+            }
+            1 => chars.insert(at, *rng.pick(alphabet)),
+            2 => chars.truncate(at),
+            3 => {
+                // Replace the number under `at`, if any, with an edge case.
+                let start = (0..at.min(chars.len()))
+                    .rev()
+                    .take_while(|&i| chars[i].is_ascii_digit())
+                    .last()
+                    .unwrap_or(at);
+                let end = (at..chars.len())
+                    .take_while(|&i| chars[i].is_ascii_digit())
+                    .last()
+                    .map_or(at, |i| i + 1);
+                chars.splice(start..end, rng.pick(EDGE_NUMBERS).chars());
+            }
+            _ => {
+                let text: String = chars.iter().collect();
+                let mut lines: Vec<&str> = text.lines().collect();
+                if lines.is_empty() {
+                    continue;
+                }
+                let i = rng.below(lines.len());
+                let j = rng.below(lines.len());
+                match rng.below(3) {
+                    0 => lines.insert(j, lines[i]),
+                    1 => {
+                        lines.remove(i);
+                    }
+                    _ => lines.swap(i, j),
+                }
+                chars = lines.join("\n").chars().collect();
+            }
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+#[test]
+fn solvers_never_panic() {
+    let exe = Path::new(env!("CARGO_BIN_EXE_aoc-2025"));
+    let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/samples"));
+    let manifest = Manifest::load(dir).unwrap();
+    let cases = env_or("AOC_FUZZ_CASES", DEFAULT_CASES);
+    let seed = env_or("AOC_FUZZ_SEED", DEFAULT_SEED);
+    let limits = Limits {
+        timeout: Some(Duration::from_secs(2)),
+        max_memory: Some(1 << 30),
+    };
+
+    let mut failures = vec![];
+    for day in days(DEFAULT_YEAR) {
+        let samples: Vec<_> = manifest
+            .for_day(day)
+            .map(|s| {
+                let input = std::fs::read_to_string(dir.join(&s.file)).unwrap();
+                (input, s.params.clone())
+            })
+            .collect();
+
+        let mut alphabet: Vec<char> = samples.iter().flat_map(|(s, _)| s.chars()).collect();
+        alphabet.extend("0123456789 \n:,-x#.".chars());
+        alphabet.sort();
+        alphabet.dedup();
+
+        for case in 0..cases {
+            let case_seed = seed ^ (u64::from(day) << 32) ^ case;
+            let mut rng = Rng::new(case_seed);
+
+            let (input, params) = if samples.is_empty() || rng.below(4) == 0 {
+                (arbitrary(&mut rng, &alphabet), toml::Table::new())
+            } else {
+                let (sample, params) = rng.pick(&samples);
+                (mutate(&mut rng, sample, &alphabet), params.clone())
+            };
+
+            let run = run_day_supervised(
+                exe,
+                DEFAULT_YEAR,
+                day,
+                &[1, 2],
+                &InputSource::Inline(input.clone()),
+                &params,
+                limits,
+            );
+
+            for record in run.records {
+                if matches!(
+                    record.status,
+                    Status::Panicked | Status::Crashed | Status::OutOfMemory
+                ) {
+                    failures.push(format!(
+                        "day {day} part {} {} (AOC_FUZZ_SEED={seed} case {case}): {}\n{input:?}",
+                        record.part,
+                        record.status.as_str(),
+                        record.message.unwrap_or_default()
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
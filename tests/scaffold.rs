@@ -0,0 +1,80 @@
+// Scaffolds a day from every template into a copy of the repository and runs
+// its test suite, so a template that breaks the build or the tests is caught
+// before anyone runs `aoc-2025 new` with it.
+
+use aoc_2025::{
+    DEFAULT_YEAR,
+    config::Config,
+    scaffold::{self, Template},
+};
+use clap::ValueEnum;
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// Everything the crate needs to build and test, but not `tests/`, which would
+// run this test again inside the copy.
+const TREE: &[&str] = &[
+    "Cargo.toml",
+    "Cargo.lock",
+    "build.rs",
+    "src",
+    "macros",
+    "samples",
+    "templates",
+];
+
+fn copy(from: &Path, to: &Path) -> std::io::Result<()> {
+    if from.is_dir() {
+        std::fs::create_dir_all(to)?;
+        for entry in std::fs::read_dir(from)? {
+            let entry = entry?;
+            if entry.file_name() != "target" {
+                copy(&entry.path(), &to.join(entry.file_name()))?;
+            }
+        }
+        Ok(())
+    } else {
+        std::fs::copy(from, to).map(|_| ())
+    }
+}
+
+#[test]
+fn scaffolded_days_pass_the_suite() {
+    let tmp = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let root = tmp.join("scaffold");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root).unwrap();
+    for path in TREE {
+        copy(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join(path),
+            &root.join(path),
+        )
+        .unwrap();
+    }
+
+    let config = Config {
+        samples_dir: root.join("samples"),
+        root: root.clone(),
+        ..Config::default()
+    };
+    for (day, template) in (20..).zip(Template::value_variants()) {
+        scaffold::create(&config, DEFAULT_YEAR, day, *template).unwrap();
+    }
+
+    let output = Command::new(env!("CARGO"))
+        .args(["test", "--offline", "--quiet"])
+        .current_dir(&root)
+        // Kept between runs so only the crate itself is rebuilt each time.
+        .env("CARGO_TARGET_DIR", tmp.join("scaffold-target"))
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
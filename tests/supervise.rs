@@ -0,0 +1,95 @@
+// Runs real worker processes to check that runaway parts are stopped and
+// reported rather than taking the runner down with them.
+
+use aoc_2025::{
+    DEFAULT_YEAR,
+    input::InputSource,
+    runner::{DayRun, Status},
+    supervise::{Limits, run_day_supervised},
+};
+use std::{path::Path, time::Duration};
+
+fn run(day: u32, input: String, limits: Limits) -> DayRun {
+    run_day_supervised(
+        Path::new(env!("CARGO_BIN_EXE_aoc-2025")),
+        DEFAULT_YEAR,
+        day,
+        &[1],
+        &InputSource::Inline(input),
+        &toml::Table::new(),
+        limits,
+    )
+}
+
+#[test]
+fn runaway_part_times_out() {
+    let limits = Limits {
+        timeout: Some(Duration::from_millis(500)),
+        ..Limits::default()
+    };
+    let run = run(2, "0-18446744073709551615".to_string(), limits);
+
+    assert_eq!(run.records[0].status, Status::TimedOut);
+    assert_eq!(
+        run.records[0].message.as_deref(),
+        Some("timed out after 500ms")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn memory_limit_is_enforced() {
+    // Day 8 sorts every pair of boxes, which is far more than 64MiB here.
+    let boxes: String = (0..3000)
+        .map(|i| format!("{},{},{i}\n", i * 7 % 1000, i * 13 % 997))
+        .collect();
+    let limits = Limits {
+        max_memory: Some(64 << 20),
+        ..Limits::default()
+    };
+    let run = run(8, boxes, limits);
+
+    assert_eq!(run.records[0].status, Status::OutOfMemory);
+    assert_eq!(
+        run.records[0].message.as_deref(),
+        Some("exceeded memory limit of 64MiB")
+    );
+}
+
+// So little memory that the worker dies from a signal instead of failing an
+// allocation, which still means it went over the limit.
+#[cfg(unix)]
+#[test]
+fn crash_under_memory_limit_is_out_of_memory() {
+    let limits = Limits {
+        max_memory: Some(8 << 20),
+        ..Limits::default()
+    };
+    let run = run(3, "987654321111111\n".to_string(), limits);
+
+    assert_eq!(run.records[0].status, Status::OutOfMemory);
+}
+
+// A stand-in worker that kills itself the way a stack overflow would.
+#[cfg(unix)]
+#[test]
+fn worker_killed_by_signal_crashed() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let exe = std::env::temp_dir().join(format!("aoc-crashing-worker-{}", std::process::id()));
+    std::fs::write(&exe, "#!/bin/sh\nkill -ABRT $$\n").unwrap();
+    std::fs::set_permissions(&exe, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let run = run_day_supervised(
+        &exe,
+        DEFAULT_YEAR,
+        3,
+        &[1],
+        &InputSource::Inline("12\n".to_string()),
+        &toml::Table::new(),
+        Limits::default(),
+    );
+    std::fs::remove_file(&exe).unwrap();
+
+    assert_eq!(run.records[0].status, Status::Crashed);
+}